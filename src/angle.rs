@@ -0,0 +1,226 @@
+//! Integration of angular rates into angles.
+
+use crate::{AngularRateRaw, Sensitivity};
+
+/// Integrated angle units per degree: quarter millidegrees times microseconds per second.
+const UNITS_PER_DEGREE: i64 = 4 * 1000 * 1_000_000;
+
+/// Integrated angle units per full turn.
+const UNITS_PER_TURN: i64 = 360 * UNITS_PER_DEGREE;
+
+/// Returns the sensitivity in quarter mdps/digit, which is integral for all full scales.
+const fn quarter_mdps_per_digit(sensitivity: Sensitivity) -> i64 {
+    sensitivity.centi_mdps_per_digit() as i64 / 25
+}
+
+/// Normalization of accumulated angles.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AngleWrap {
+    /// Angles are not normalized and accumulate full turns.
+    #[default]
+    None,
+    /// Angles are normalized to `[-180°, 180°)`.
+    Signed,
+    /// Angles are normalized to `[0°, 360°)`, e.g. for headings.
+    Unsigned,
+}
+
+impl AngleWrap {
+    const fn apply(self, value: i64) -> i64 {
+        match self {
+            AngleWrap::None => value,
+            AngleWrap::Signed => {
+                (value + UNITS_PER_TURN / 2).rem_euclid(UNITS_PER_TURN) - UNITS_PER_TURN / 2
+            }
+            AngleWrap::Unsigned => value.rem_euclid(UNITS_PER_TURN),
+        }
+    }
+}
+
+/// Integrates raw angular rate readings into angles.
+///
+/// Readings are accumulated exactly as integers and only converted to degrees when queried,
+/// avoiding the drift of repeated floating-point additions. Since the sensitivity is applied
+/// per sample, it may change between samples.
+///
+/// Without wrapping, accumulation saturates instead of overflowing, which takes about
+/// 11.6 days of continuous rotation at ±2000 dps full scale. Use [`AngleWrap::Signed`] or
+/// [`AngleWrap::Unsigned`] for long-running headings, or check
+/// [`is_saturated`](Self::is_saturated).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AngleAccumulator {
+    acc: [i64; 3],
+    wrap: AngleWrap,
+}
+
+impl AngleAccumulator {
+    /// Creates an accumulator at zero angle that does not normalize angles.
+    pub const fn new() -> Self {
+        Self {
+            acc: [0; 3],
+            wrap: AngleWrap::None,
+        }
+    }
+
+    /// Sets the normalization applied after every sample.
+    pub const fn with_wrap(mut self, wrap: AngleWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Returns the normalization applied after every sample.
+    pub const fn wrap(&self) -> AngleWrap {
+        self.wrap
+    }
+
+    /// Adds a reading held for `dt_us` microseconds.
+    pub fn add(&mut self, raw: AngularRateRaw, sensitivity: Sensitivity, dt_us: u32) {
+        let scale = quarter_mdps_per_digit(sensitivity) * dt_us as i64;
+        for (acc, value) in self.acc.iter_mut().zip([raw.x, raw.y, raw.z]) {
+            *acc = self.wrap.apply(acc.saturating_add(value as i64 * scale));
+        }
+    }
+
+    /// Normalizes the accumulated angles once, e.g. before reading a heading.
+    pub fn normalize(&mut self, wrap: AngleWrap) {
+        for acc in self.acc.iter_mut() {
+            *acc = wrap.apply(*acc);
+        }
+    }
+
+    /// Determines whether the angle of any axis has saturated and no longer follows the
+    /// readings. Only possible with [`AngleWrap::None`].
+    pub fn is_saturated(&self) -> bool {
+        self.acc
+            .iter()
+            .any(|&acc| acc == i64::MAX || acc == i64::MIN)
+    }
+
+    /// Resets all angles to zero.
+    pub fn reset(&mut self) {
+        self.acc = [0; 3];
+    }
+
+    /// Returns the accumulated angles of the X, Y and Z axes in degrees.
+    pub fn degrees(&self) -> [f32; 3] {
+        self.acc
+            .map(|acc| (acc as f64 / UNITS_PER_DEGREE as f64) as f32)
+    }
+
+    /// Returns the accumulated angles of the X, Y and Z axes in radians.
+    pub fn radians(&self) -> [f32; 3] {
+        self.degrees().map(|deg| deg.to_radians())
+    }
+
+    /// Returns the accumulated X-axis angle in degrees.
+    pub fn x_degrees(&self) -> f32 {
+        self.degrees()[0]
+    }
+
+    /// Returns the accumulated Y-axis angle in degrees.
+    pub fn y_degrees(&self) -> f32 {
+        self.degrees()[1]
+    }
+
+    /// Returns the accumulated Z-axis angle in degrees.
+    pub fn z_degrees(&self) -> f32 {
+        self.degrees()[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND_US: u32 = 1_000_000;
+
+    #[test]
+    fn integrates_exactly() {
+        let mut angle = AngleAccumulator::new();
+        for _ in 0..100 {
+            angle.add(
+                AngularRateRaw::new(1000, -1000, 0),
+                Sensitivity::D250,
+                SECOND_US / 100,
+            );
+        }
+        assert_eq!(angle.degrees(), [8.75, -8.75, 0.0]);
+    }
+
+    #[test]
+    fn applies_the_sensitivity_per_sample() {
+        let mut angle = AngleAccumulator::new();
+        angle.add(
+            AngularRateRaw::new(1000, 0, 0),
+            Sensitivity::D250,
+            SECOND_US,
+        );
+        angle.add(
+            AngularRateRaw::new(1000, 0, 0),
+            Sensitivity::D500,
+            SECOND_US,
+        );
+        angle.add(
+            AngularRateRaw::new(1000, 0, 0),
+            Sensitivity::D2000_11,
+            SECOND_US,
+        );
+        assert_eq!(angle.x_degrees(), 8.75 + 17.5 + 70.0);
+    }
+
+    #[test]
+    fn wraps_signed_and_unsigned() {
+        let mut signed = AngleAccumulator::new().with_wrap(AngleWrap::Signed);
+        let mut unsigned = AngleAccumulator::new().with_wrap(AngleWrap::Unsigned);
+        for _ in 0..21 {
+            signed.add(
+                AngularRateRaw::new(0, 0, 1000),
+                Sensitivity::D250,
+                SECOND_US,
+            );
+            unsigned.add(
+                AngularRateRaw::new(0, 0, -1000),
+                Sensitivity::D250,
+                SECOND_US,
+            );
+        }
+        // 21 × 8.75° = 183.75°
+        assert_eq!(signed.z_degrees(), 183.75 - 360.0);
+        assert_eq!(unsigned.z_degrees(), 360.0 - 183.75);
+    }
+
+    #[test]
+    fn normalize_wraps_once() {
+        let mut angle = AngleAccumulator::new();
+        for _ in 0..42 {
+            angle.add(
+                AngularRateRaw::new(0, 1000, 0),
+                Sensitivity::D250,
+                SECOND_US,
+            );
+        }
+        assert_eq!(angle.y_degrees(), 367.5);
+        angle.normalize(AngleWrap::Unsigned);
+        assert_eq!(angle.y_degrees(), 7.5);
+        assert_eq!(angle.wrap(), AngleWrap::None);
+
+        angle.reset();
+        assert_eq!(angle.degrees(), [0.0; 3]);
+    }
+
+    #[test]
+    fn saturates_without_wrapping() {
+        let max = AngularRateRaw::new(i16::MAX, 0, 0);
+        let mut unwrapped = AngleAccumulator::new();
+        let mut wrapped = AngleAccumulator::new().with_wrap(AngleWrap::Signed);
+        for _ in 0..300 {
+            unwrapped.add(max, Sensitivity::D2000, u32::MAX);
+            wrapped.add(max, Sensitivity::D2000, u32::MAX);
+        }
+        assert!(unwrapped.is_saturated());
+        assert!(!wrapped.is_saturated());
+        assert!((-180.0..180.0).contains(&wrapped.x_degrees()));
+    }
+}
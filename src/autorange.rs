@@ -0,0 +1,213 @@
+//! Automatic full-scale selection.
+
+use crate::{AngularRateRaw, ControlRegister4, RegisterBus, Sensitivity};
+
+/// Selects the full scale automatically based on the observed readings.
+///
+/// The controller steps up to the next larger full scale as soon as any axis approaches
+/// saturation, and steps down to the next smaller full scale once the readings would have
+/// fit comfortably for a number of consecutive samples. The gap between both thresholds
+/// and the hold time prevent oscillation.
+///
+/// When [`observe`](Self::observe) returns a new sensitivity, write it to
+/// [`ControlRegister4::full_scale`] and scale subsequent samples with
+/// [`sensitivity`](Self::sensitivity), or use [`observe_and_apply`](Self::observe_and_apply)
+/// to update the sensor through a [`RegisterBus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AutoRange {
+    sensitivity: Sensitivity,
+    up_threshold: u16,
+    down_threshold: u16,
+    hold_samples: u16,
+    quiet_samples: u16,
+}
+
+impl AutoRange {
+    /// The default threshold for stepping up, about 90% of the digital range.
+    pub const DEFAULT_UP_THRESHOLD: u16 = 29_500;
+
+    /// The default threshold for stepping down, about 40% of the digital range
+    /// of the next smaller full scale.
+    pub const DEFAULT_DOWN_THRESHOLD: u16 = 13_000;
+
+    /// The default number of consecutive samples required before stepping down.
+    pub const DEFAULT_HOLD_SAMPLES: u16 = 32;
+
+    /// Creates a controller starting at the specified full scale.
+    pub const fn new(sensitivity: Sensitivity) -> Self {
+        Self {
+            sensitivity,
+            up_threshold: Self::DEFAULT_UP_THRESHOLD,
+            down_threshold: Self::DEFAULT_DOWN_THRESHOLD,
+            hold_samples: Self::DEFAULT_HOLD_SAMPLES,
+            quiet_samples: 0,
+        }
+    }
+
+    /// Sets the thresholds in digits.
+    ///
+    /// The controller steps up when any axis reaches `up`, and steps down when all axes
+    /// would read below `down` at the next smaller full scale.
+    pub const fn with_thresholds(mut self, up: u16, down: u16) -> Self {
+        self.up_threshold = up;
+        self.down_threshold = down;
+        self
+    }
+
+    /// Sets the number of consecutive samples required before stepping down.
+    pub const fn with_hold_samples(mut self, samples: u16) -> Self {
+        self.hold_samples = samples;
+        self
+    }
+
+    /// Returns the currently selected full scale.
+    pub const fn sensitivity(&self) -> Sensitivity {
+        self.sensitivity
+    }
+
+    /// Observes a reading taken at the currently selected full scale.
+    ///
+    /// Returns the new full scale if it changed.
+    pub fn observe(&mut self, raw: AngularRateRaw) -> Option<Sensitivity> {
+        let peak = raw
+            .x
+            .unsigned_abs()
+            .max(raw.y.unsigned_abs())
+            .max(raw.z.unsigned_abs());
+
+        if peak >= self.up_threshold {
+            self.quiet_samples = 0;
+            let larger = larger(self.sensitivity)?;
+            self.sensitivity = larger;
+            return Some(larger);
+        }
+
+        let smaller = smaller(self.sensitivity)?;
+
+        let rescaled =
+            peak as u32 * self.sensitivity.centi_mdps_per_digit() / smaller.centi_mdps_per_digit();
+        if rescaled >= self.down_threshold as u32 {
+            self.quiet_samples = 0;
+            return None;
+        }
+
+        self.quiet_samples = self.quiet_samples.saturating_add(1);
+        if self.quiet_samples < self.hold_samples {
+            return None;
+        }
+
+        self.quiet_samples = 0;
+        self.sensitivity = smaller;
+        Some(smaller)
+    }
+
+    /// Observes a reading like [`observe`](Self::observe) and writes a changed full scale to
+    /// [`CTRL_REG4`](crate::RegisterAddress::CTRL_REG4).
+    ///
+    /// The controller state is only updated if the write succeeds, so a failed write is
+    /// retried on the next reading.
+    pub fn observe_and_apply<B>(
+        &mut self,
+        bus: &mut B,
+        raw: AngularRateRaw,
+    ) -> Result<Option<Sensitivity>, B::Error>
+    where
+        B: RegisterBus,
+    {
+        let mut next = *self;
+        let changed = next.observe(raw);
+        if let Some(sensitivity) = changed {
+            bus.modify::<ControlRegister4, _>(|register| register.with_full_scale(sensitivity))?;
+        }
+        *self = next;
+        Ok(changed)
+    }
+}
+
+const fn larger(sensitivity: Sensitivity) -> Option<Sensitivity> {
+    match sensitivity {
+        Sensitivity::D250 => Some(Sensitivity::D500),
+        Sensitivity::D500 => Some(Sensitivity::D2000),
+        Sensitivity::D2000 | Sensitivity::D2000_11 => None,
+    }
+}
+
+const fn smaller(sensitivity: Sensitivity) -> Option<Sensitivity> {
+    match sensitivity {
+        Sensitivity::D250 => None,
+        Sensitivity::D500 => Some(Sensitivity::D250),
+        Sensitivity::D2000 | Sensitivity::D2000_11 => Some(Sensitivity::D500),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusTransaction, RegisterAddress, Replay};
+
+    #[test]
+    fn steps_up_near_saturation() {
+        let mut range = AutoRange::new(Sensitivity::D250);
+        assert_eq!(
+            range.observe(AngularRateRaw::new(0, 29_500, 0)),
+            Some(Sensitivity::D500)
+        );
+        assert_eq!(
+            range.observe(AngularRateRaw::new(-29_500, 0, 0)),
+            Some(Sensitivity::D2000)
+        );
+        assert_eq!(range.observe(AngularRateRaw::new(0, 0, i16::MAX)), None);
+        assert_eq!(range.sensitivity(), Sensitivity::D2000);
+    }
+
+    #[test]
+    fn steps_down_after_hold_time() {
+        let mut range = AutoRange::new(Sensitivity::D500).with_hold_samples(3);
+        let quiet = AngularRateRaw::new(100, -100, 0);
+        assert_eq!(range.observe(quiet), None);
+        assert_eq!(range.observe(quiet), None);
+        assert_eq!(range.observe(quiet), Some(Sensitivity::D250));
+    }
+
+    #[test]
+    fn loud_reading_resets_hold_time() {
+        let mut range = AutoRange::new(Sensitivity::D500).with_hold_samples(2);
+        assert_eq!(range.observe(AngularRateRaw::new(0, 0, 0)), None);
+        // 7000 digits at 500 dps would read 14000 digits at 250 dps, above the down threshold.
+        assert_eq!(range.observe(AngularRateRaw::new(7_000, 0, 0)), None);
+        assert_eq!(range.observe(AngularRateRaw::new(0, 0, 0)), None);
+        assert_eq!(
+            range.observe(AngularRateRaw::new(0, 0, 0)),
+            Some(Sensitivity::D250)
+        );
+    }
+
+    #[test]
+    fn observe_and_apply_writes_ctrl_reg4() {
+        let log = [
+            BusTransaction::read(RegisterAddress::CTRL_REG4, &[0x80]),
+            BusTransaction::write(RegisterAddress::CTRL_REG4, &[0x90]),
+        ];
+        let mut bus = Replay::new(&log);
+        let mut range = AutoRange::new(Sensitivity::D250);
+
+        let quiet = AngularRateRaw::new(0, 0, 0);
+        assert_eq!(range.observe_and_apply(&mut bus, quiet), Ok(None));
+        let loud = AngularRateRaw::new(30_000, 0, 0);
+        assert_eq!(
+            range.observe_and_apply(&mut bus, loud),
+            Ok(Some(Sensitivity::D500))
+        );
+        assert_eq!(range.sensitivity(), Sensitivity::D500);
+    }
+
+    #[test]
+    fn observe_and_apply_keeps_state_on_error() {
+        let mut bus = Replay::new(&[]);
+        let mut range = AutoRange::new(Sensitivity::D250);
+        let loud = AngularRateRaw::new(30_000, 0, 0);
+        assert!(range.observe_and_apply(&mut bus, loud).is_err());
+        assert_eq!(range.sensitivity(), Sensitivity::D250);
+    }
+}
@@ -0,0 +1,335 @@
+//! Raw buffers for burst and DMA transfers.
+
+use crate::{
+    AngularRateRaw, FifoSourceRegister, Measurement, OutputDataRate, RegisterAddress, RegisterBus,
+};
+
+/// The number of 6-byte samples the hardware FIFO can hold.
+pub const FIFO_DEPTH: usize = 32;
+
+/// A buffer for a burst read of the output data block, [`OUT_X_L`](crate::RegisterAddress::OUT_X_L)
+/// (28h) through [`OUT_Z_H`](crate::RegisterAddress::OUT_Z_H) (2Dh).
+///
+/// The buffer has a C-compatible layout and is aligned to 16 bits, so that it can be
+/// handed to byte- or half-word DMA transfers directly.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C, align(2))]
+pub struct OutputBuffer(pub [u8; 6]);
+
+impl OutputBuffer {
+    /// Creates a zeroed buffer.
+    pub const fn new() -> Self {
+        Self([0; 6])
+    }
+
+    /// Returns the raw bytes.
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Returns the raw bytes for writing, e.g. as a DMA target.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8; 6] {
+        &mut self.0
+    }
+
+    /// Decodes the buffer assuming little endian data order.
+    pub const fn decode(&self) -> AngularRateRaw {
+        AngularRateRaw::from_le_bytes(self.0)
+    }
+}
+
+/// A buffer for a burst read of [`OUT_TEMP`](crate::RegisterAddress::OUT_TEMP) (26h)
+/// through [`OUT_Z_H`](crate::RegisterAddress::OUT_Z_H) (2Dh). See [`Measurement`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C, align(2))]
+pub struct MeasurementBuffer(pub [u8; 8]);
+
+impl MeasurementBuffer {
+    /// Creates a zeroed buffer.
+    pub const fn new() -> Self {
+        Self([0; 8])
+    }
+
+    /// Returns the raw bytes.
+    pub const fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// Returns the raw bytes for writing, e.g. as a DMA target.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8; 8] {
+        &mut self.0
+    }
+
+    /// Decodes the buffer assuming little endian data order.
+    pub const fn decode(&self) -> Measurement {
+        Measurement::from_le_bytes(self.0)
+    }
+}
+
+/// A buffer for a burst read of FIFO contents starting at [`OUT_X_L`](crate::RegisterAddress::OUT_X_L).
+///
+/// In FIFO mode, the register address wraps from `OUT_Z_H` back to `OUT_X_L` during
+/// auto-increment reads, so a single transfer yields consecutive 6-byte frames.
+///
+/// `N` is the size of the buffer in bytes and must be a multiple of 6, which is checked at
+/// compile time when the buffer is created; use [`FullFifoBuffer`] to hold the entire FIFO.
+/// The buffer has a C-compatible layout and is aligned to 16 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C, align(2))]
+pub struct FifoBuffer<const N: usize>([u8; N]);
+
+/// A [`FifoBuffer`] holding all [`FIFO_DEPTH`] frames of the hardware FIFO.
+pub type FullFifoBuffer = FifoBuffer<{ FIFO_DEPTH * 6 }>;
+
+impl<const N: usize> FifoBuffer<N> {
+    /// The number of frames the buffer can hold.
+    pub const FRAMES: usize = N / 6;
+
+    const VALID_SIZE: () = assert!(N % 6 == 0, "FIFO buffer size must be a multiple of 6");
+
+    /// Creates a zeroed buffer.
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SIZE;
+        Self([0; N])
+    }
+
+    /// Returns the raw bytes.
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the raw bytes for writing, e.g. as a DMA target.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+
+    /// Returns the bytes required to read `frames` frames, e.g. the FIFO level
+    /// reported by [`FifoSourceRegister::fss`](crate::FifoSourceRegister::fss).
+    ///
+    /// The number of frames is limited to the buffer capacity.
+    pub fn bytes_for_mut(&mut self, frames: usize) -> &mut [u8] {
+        let len = frames.min(Self::FRAMES) * 6;
+        &mut self.0[..len]
+    }
+
+    /// Reads and decodes the frames stored in the FIFO.
+    ///
+    /// Determines the number of stored frames from the value of `FIFO_SRC_REG`, then calls
+    /// `read` once with [`OUT_X_L`](crate::RegisterAddress::OUT_X_L) and a correctly sized
+    /// slice of this buffer. `read` performs the burst read; the auto-increment bit
+    /// ([`SPI_MULTI`](crate::SPI_MULTI) or [`I2C_AUTO_INCREMENT`](crate::I2C_AUTO_INCREMENT))
+    /// must be set by the caller as required by the bus. `read` is not called if the FIFO is empty.
+    ///
+    /// Returns an iterator over the frames, oldest first, assuming little endian data order.
+    pub fn drain<F, E>(
+        &mut self,
+        source: FifoSourceRegister,
+        read: F,
+    ) -> Result<impl Iterator<Item = AngularRateRaw> + '_, E>
+    where
+        F: FnOnce(RegisterAddress, &mut [u8]) -> Result<(), E>,
+    {
+        let frames = source.stored_frames().min(Self::FRAMES);
+        if frames > 0 {
+            read(AngularRateRaw::START_ADDRESS, self.bytes_for_mut(frames))?;
+        }
+        Ok(self.frames(frames))
+    }
+
+    /// Reads [`FIFO_SRC_REG`](crate::RegisterAddress::FIFO_SRC_REG) and the stored frames
+    /// through a [`RegisterBus`], see [`drain`](Self::drain).
+    pub fn drain_bus<B>(
+        &mut self,
+        bus: &mut B,
+    ) -> Result<impl Iterator<Item = AngularRateRaw> + '_, B::Error>
+    where
+        B: RegisterBus,
+    {
+        let frames = bus
+            .read::<FifoSourceRegister>()?
+            .stored_frames()
+            .min(Self::FRAMES);
+        if frames > 0 {
+            bus.read_registers(AngularRateRaw::START_ADDRESS, self.bytes_for_mut(frames))?;
+        }
+        Ok(self.frames(frames))
+    }
+
+    /// Decodes a single frame assuming little endian data order.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn frame(&self, index: usize) -> Option<AngularRateRaw> {
+        if index >= Self::FRAMES {
+            return None;
+        }
+
+        let b = &self.0[index * 6..index * 6 + 6];
+        Some(AngularRateRaw::from_le_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5],
+        ]))
+    }
+
+    /// Iterates the first `frames` frames assuming little endian data order.
+    ///
+    /// The number of frames is limited to the buffer capacity.
+    pub fn frames(&self, frames: usize) -> impl Iterator<Item = AngularRateRaw> + '_ {
+        (0..frames.min(Self::FRAMES)).filter_map(move |index| self.frame(index))
+    }
+}
+
+impl<const N: usize> Default for FifoBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads and decodes the frames stored in the FIFO into a vector.
+///
+/// See [`FifoBuffer::drain`] for details.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn drain_fifo_vec<F, E>(
+    source: FifoSourceRegister,
+    read: F,
+) -> Result<alloc::vec::Vec<AngularRateRaw>, E>
+where
+    F: FnOnce(RegisterAddress, &mut [u8]) -> Result<(), E>,
+{
+    Ok(FullFifoBuffer::new().drain(source, read)?.collect())
+}
+
+impl FifoSourceRegister {
+    /// Returns the number of frames stored in the FIFO.
+    ///
+    /// The FIFO level reported by [`fss`](Self::fss) can only represent up to 31 frames;
+    /// a full FIFO is indicated by [`ovrn_fifo`](Self::ovrn_fifo) and holds [`FIFO_DEPTH`] frames.
+    pub const fn stored_frames(&self) -> usize {
+        if self.ovrn_fifo() {
+            FIFO_DEPTH
+        } else if self.empty() {
+            0
+        } else {
+            self.fss() as usize
+        }
+    }
+}
+
+/// Timing of FIFO batching with a watermark interrupt, e.g. to trade interrupt rate against
+/// data latency.
+///
+/// The FIFO raises the watermark interrupt once `watermark` frames are stored. The oldest
+/// frame of a batch has then waited `watermark` sample periods, which is the worst-case
+/// latency before the host is notified. After the interrupt, the host must drain the FIFO
+/// before the remaining capacity fills up, or frames are lost in stream mode.
+///
+/// All times are based on the nominal output data rate and do not include bus transfer times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WatermarkTiming {
+    period_us: u32,
+    watermark: u8,
+}
+
+impl WatermarkTiming {
+    /// Creates the timing for an output data rate and watermark level.
+    ///
+    /// The watermark is limited to `1..=31` frames, see
+    /// [`FifoControlRegister::watermark`](crate::FifoControlRegister::watermark).
+    pub const fn new(odr: OutputDataRate, watermark: u8) -> Self {
+        let watermark = if watermark == 0 {
+            1
+        } else if watermark as usize >= FIFO_DEPTH {
+            (FIFO_DEPTH - 1) as u8
+        } else {
+            watermark
+        };
+        Self {
+            period_us: odr.period_us(),
+            watermark,
+        }
+    }
+
+    /// Returns the effective watermark level in frames.
+    pub const fn watermark(&self) -> u8 {
+        self.watermark
+    }
+
+    /// Returns the worst-case time in microseconds between sampling a frame and the
+    /// watermark interrupt reporting it.
+    pub const fn worst_case_latency_us(&self) -> u32 {
+        self.watermark as u32 * self.period_us
+    }
+
+    /// Returns the time between two watermark interrupts in microseconds, assuming the
+    /// FIFO is drained completely on each interrupt.
+    pub const fn interrupt_period_us(&self) -> u32 {
+        self.worst_case_latency_us()
+    }
+
+    /// Returns the number of watermark interrupts per second in millihertz.
+    pub const fn interrupt_rate_millihz(&self) -> u32 {
+        1_000_000_000 / self.interrupt_period_us()
+    }
+
+    /// Returns the time in microseconds the host has to drain the FIFO after the watermark
+    /// interrupt before the FIFO is full.
+    pub const fn drain_deadline_us(&self) -> u32 {
+        (FIFO_DEPTH as u32 - self.watermark as u32) * self.period_us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusTransaction, Replay};
+
+    #[test]
+    fn stored_frames_covers_full_and_empty_fifo() {
+        assert_eq!(
+            FifoSourceRegister::from_bits(0b0010_0000).stored_frames(),
+            0
+        );
+        assert_eq!(
+            FifoSourceRegister::from_bits(0b0000_0011).stored_frames(),
+            3
+        );
+        assert_eq!(
+            FifoSourceRegister::from_bits(0b0100_0000).stored_frames(),
+            FIFO_DEPTH
+        );
+    }
+
+    #[test]
+    fn drain_bus_reads_stored_frames() {
+        let log = [
+            BusTransaction::read(RegisterAddress::FIFO_SRC_REG, &[0b0000_0010]),
+            BusTransaction::read(
+                RegisterAddress::OUT_X_L,
+                &[1, 0, 2, 0, 3, 0, 0xFF, 0xFF, 0xFE, 0xFF, 0xFD, 0xFF],
+            ),
+        ];
+        let mut bus = Replay::new(&log);
+        let mut buffer = FullFifoBuffer::new();
+        let mut frames = buffer.drain_bus(&mut bus).unwrap();
+        assert_eq!(frames.next(), Some(AngularRateRaw::new(1, 2, 3)));
+        assert_eq!(frames.next(), Some(AngularRateRaw::new(-1, -2, -3)));
+        assert_eq!(frames.next(), None);
+        assert!(bus.is_finished());
+    }
+
+    #[test]
+    fn drain_bus_skips_empty_fifo() {
+        let log = [BusTransaction::read(
+            RegisterAddress::FIFO_SRC_REG,
+            &[0b0010_0000],
+        )];
+        let mut bus = Replay::new(&log);
+        let mut buffer = FifoBuffer::<12>::new();
+        assert_eq!(buffer.drain_bus(&mut bus).unwrap().count(), 0);
+        assert!(bus.is_finished());
+    }
+}
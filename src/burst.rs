@@ -0,0 +1,247 @@
+//! Helpers for multi-byte (auto-increment) transfers.
+
+use crate::RegisterAddress;
+
+/// The SPI `RW` bit; set for reads, cleared for writes.
+pub const SPI_READ: u8 = 0b1000_0000;
+
+/// The SPI `MS` bit; when set, the register address is incremented after each byte.
+pub const SPI_MULTI: u8 = 0b0100_0000;
+
+/// The I²C sub-address MSb; when set, the register address is incremented after each byte.
+pub const I2C_AUTO_INCREMENT: u8 = 0b1000_0000;
+
+/// Packs consecutive register values into a single SPI multi-byte write.
+///
+/// The first byte of the frame is the address of `start` with the `MS` bit set and the
+/// `RW` bit cleared, followed by `values` in register order. All registers covered by the
+/// write must be writable, e.g. `CTRL_REG1` (20h) through `REFERENCE` (25h) or
+/// `INT1_TSH_XH` (32h) through `INT1_DURATION` (38h).
+///
+/// Returns the packed frame, i.e. the first `values.len() + 1` bytes of `buffer`.
+pub fn pack_spi_write<'a>(
+    start: RegisterAddress,
+    values: &[u8],
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8], PackError> {
+    if values.is_empty() {
+        return Err(PackError::Empty);
+    }
+
+    let required = values.len() + 1;
+    if buffer.len() < required {
+        return Err(PackError::BufferTooSmall {
+            required,
+            actual: buffer.len(),
+        });
+    }
+
+    let mut addr = start.addr();
+    for _ in values {
+        match RegisterAddress::from_addr(addr) {
+            Some(register) if register.is_writable() => {}
+            _ => return Err(PackError::NotWritable(addr)),
+        }
+        addr = addr.wrapping_add(1);
+    }
+
+    buffer[0] = start.addr() | SPI_MULTI;
+    buffer[1..required].copy_from_slice(values);
+    Ok(&buffer[..required])
+}
+
+/// An error when packing a multi-byte write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackError {
+    /// No values were provided.
+    Empty,
+    /// The output buffer cannot hold the packed frame.
+    BufferTooSmall {
+        /// The number of bytes required.
+        required: usize,
+        /// The number of bytes available.
+        actual: usize,
+    },
+    /// The write would cover a read-only or reserved register at the specified address.
+    NotWritable(u8),
+}
+
+impl core::fmt::Display for PackError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PackError::Empty => write!(f, "no register values to write"),
+            PackError::BufferTooSmall { required, actual } => write!(
+                f,
+                "buffer has {actual} bytes, but {required} bytes are required"
+            ),
+            PackError::NotWritable(address) => {
+                write!(f, "register at {address:02X}h is not writable")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for PackError {}
+
+/// Plans auto-increment transactions covering a set of registers.
+///
+/// [`read_runs`](Self::read_runs) minimizes the number of read transactions by also covering
+/// registers between requested ones, as long as they are documented and reading them has no
+/// side effects (see [`RegisterAddress::has_read_side_effects`]). [`write_runs`](Self::write_runs)
+/// only combines adjacent requested registers, so no other register is overwritten.
+///
+/// Each run is a start register and a length in bytes, for use with
+/// [`I2C_AUTO_INCREMENT`] or [`SPI_MULTI`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransactionPlan {
+    requested: u64,
+}
+
+impl TransactionPlan {
+    /// Creates an empty plan.
+    pub const fn new() -> Self {
+        Self { requested: 0 }
+    }
+
+    /// Adds a register to the plan.
+    #[must_use]
+    pub const fn with(mut self, register: RegisterAddress) -> Self {
+        self.requested |= 1 << register.addr();
+        self
+    }
+
+    /// Adds a register to the plan.
+    pub fn insert(&mut self, register: RegisterAddress) {
+        *self = self.with(register);
+    }
+
+    /// Determines whether the register is part of the plan.
+    pub const fn contains(&self, register: RegisterAddress) -> bool {
+        self.requested & (1 << register.addr()) != 0
+    }
+
+    /// Determines whether no register was requested.
+    pub const fn is_empty(&self) -> bool {
+        self.requested == 0
+    }
+
+    /// Iterates the read transactions covering all requested registers, in address order.
+    pub const fn read_runs(&self) -> TransactionRuns {
+        TransactionRuns {
+            plan: *self,
+            next: 0,
+            bridge: true,
+        }
+    }
+
+    /// Iterates the write transactions covering exactly the requested registers,
+    /// in address order.
+    pub const fn write_runs(&self) -> TransactionRuns {
+        TransactionRuns {
+            plan: *self,
+            next: 0,
+            bridge: false,
+        }
+    }
+
+    /// Determines whether an unrequested register may be read as part of a run.
+    const fn can_bridge(addr: u8) -> bool {
+        match RegisterAddress::from_addr(addr) {
+            Some(register) => !register.has_read_side_effects(),
+            None => false,
+        }
+    }
+
+    const fn is_requested(&self, addr: u8) -> bool {
+        addr < 64 && self.requested & (1 << addr) != 0
+    }
+}
+
+impl FromIterator<RegisterAddress> for TransactionPlan {
+    fn from_iter<T: IntoIterator<Item = RegisterAddress>>(iter: T) -> Self {
+        let mut plan = Self::new();
+        plan.extend(iter);
+        plan
+    }
+}
+
+impl Extend<RegisterAddress> for TransactionPlan {
+    fn extend<T: IntoIterator<Item = RegisterAddress>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|register| self.insert(register));
+    }
+}
+
+/// An iterator over the `(start, len)` runs of a [`TransactionPlan`].
+#[derive(Debug, Clone)]
+pub struct TransactionRuns {
+    plan: TransactionPlan,
+    next: u8,
+    bridge: bool,
+}
+
+impl Iterator for TransactionRuns {
+    type Item = (RegisterAddress, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut addr = self.next;
+        while addr < 64 && !self.plan.is_requested(addr) {
+            addr += 1;
+        }
+        let start = RegisterAddress::from_addr(addr)?;
+
+        // The address of the last requested register of the run.
+        let mut end = addr;
+        let mut probe = addr + 1;
+        while probe < 64 {
+            if self.plan.is_requested(probe) {
+                end = probe;
+            } else if !self.bridge || !TransactionPlan::can_bridge(probe) {
+                break;
+            }
+            probe += 1;
+        }
+
+        self.next = end + 1;
+        Some((start, usize::from(end - addr) + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_runs_do_not_bridge_output_registers() {
+        let plan = TransactionPlan::new()
+            .with(RegisterAddress::STATUS_REG)
+            .with(RegisterAddress::FIFO_SRC_REG);
+        let mut runs = plan.read_runs();
+        assert_eq!(runs.next(), Some((RegisterAddress::STATUS_REG, 1)));
+        assert_eq!(runs.next(), Some((RegisterAddress::FIFO_SRC_REG, 1)));
+        assert_eq!(runs.next(), None);
+    }
+
+    #[test]
+    fn read_runs_cover_requested_output_registers() {
+        let plan = TransactionPlan::new()
+            .with(RegisterAddress::OUT_TEMP)
+            .with(RegisterAddress::OUT_Z_H);
+        let mut runs = plan.read_runs();
+        assert_eq!(runs.next(), Some((RegisterAddress::OUT_TEMP, 1)));
+        assert_eq!(runs.next(), Some((RegisterAddress::OUT_Z_H, 1)));
+        assert_eq!(runs.next(), None);
+    }
+
+    #[test]
+    fn read_runs_bridge_control_registers() {
+        let plan = TransactionPlan::new()
+            .with(RegisterAddress::CTRL_REG1)
+            .with(RegisterAddress::CTRL_REG5);
+        let mut runs = plan.read_runs();
+        assert_eq!(runs.next(), Some((RegisterAddress::CTRL_REG1, 5)));
+        assert_eq!(runs.next(), None);
+    }
+}
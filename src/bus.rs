@@ -0,0 +1,133 @@
+//! A minimal transport abstraction for register access.
+
+use crate::{AngularRateRaw, Measurement, Register, RegisterAddress, WritableRegister};
+
+/// Register access over an arbitrary transport, e.g. bit-banged SPI, I3C bridges or
+/// RPC-over-UART setups.
+///
+/// Implementations only need to provide the burst transfers; they are responsible for
+/// setting the auto-increment bit ([`SPI_MULTI`](crate::SPI_MULTI) or
+/// [`I2C_AUTO_INCREMENT`](crate::I2C_AUTO_INCREMENT)) for transfers longer than one byte.
+/// The single-register and typed accessors are provided on top.
+pub trait RegisterBus {
+    /// The error type of the transport.
+    type Error;
+
+    /// Reads consecutive registers starting at `start` into `buffer`.
+    fn read_registers(
+        &mut self,
+        start: RegisterAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+
+    /// Writes `values` to consecutive registers starting at `start`.
+    fn write_registers(&mut self, start: RegisterAddress, values: &[u8])
+        -> Result<(), Self::Error>;
+
+    /// Reads a single register.
+    fn read_register(&mut self, register: RegisterAddress) -> Result<u8, Self::Error> {
+        let mut buffer = [0];
+        self.read_registers(register, &mut buffer)?;
+        Ok(buffer[0])
+    }
+
+    /// Writes a single register.
+    fn write_register(&mut self, register: RegisterAddress, value: u8) -> Result<(), Self::Error> {
+        self.write_registers(register, &[value])
+    }
+
+    /// Reads a typed register.
+    fn read<R: Register>(&mut self) -> Result<R, Self::Error> {
+        self.read_register(R::ADDRESS).map(R::from_byte)
+    }
+
+    /// Writes a typed register.
+    fn write<R: WritableRegister>(&mut self, register: R) -> Result<(), Self::Error> {
+        self.write_register(R::ADDRESS, register.to_byte())
+    }
+
+    /// Reads, modifies and writes back a typed register.
+    fn modify<R, F>(&mut self, f: F) -> Result<R, Self::Error>
+    where
+        R: WritableRegister,
+        F: FnOnce(R) -> R,
+    {
+        let register = f(self.read::<R>()?);
+        self.write(register)?;
+        Ok(register)
+    }
+
+    /// Reads the output data block in a single burst, assuming little endian data order.
+    fn read_angular_rate_raw(&mut self) -> Result<AngularRateRaw, Self::Error> {
+        let mut buffer = [0; AngularRateRaw::LEN];
+        self.read_registers(AngularRateRaw::START_ADDRESS, &mut buffer)?;
+        Ok(AngularRateRaw::from_le_bytes(buffer))
+    }
+
+    /// Reads temperature, status and angular rate in a single burst, assuming little endian
+    /// data order.
+    fn read_measurement(&mut self) -> Result<Measurement, Self::Error> {
+        let mut buffer = [0; Measurement::LEN];
+        self.read_registers(Measurement::START_ADDRESS, &mut buffer)?;
+        Ok(Measurement::from_le_bytes(buffer))
+    }
+
+    /// Reads temperature and angular rate in a single burst, so that both are time-aligned,
+    /// assuming little endian data order.
+    ///
+    /// [`STATUS_REG`](RegisterAddress::STATUS_REG) lies between the two and is read as part
+    /// of the burst, but discarded; use [`read_measurement`](Self::read_measurement) to keep it.
+    fn read_temp_and_rate(&mut self) -> Result<(i8, AngularRateRaw), Self::Error> {
+        let measurement = self.read_measurement()?;
+        Ok((measurement.temp, measurement.rate))
+    }
+}
+
+impl<B> RegisterBus for &mut B
+where
+    B: RegisterBus,
+{
+    type Error = B::Error;
+
+    fn read_registers(
+        &mut self,
+        start: RegisterAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        (**self).read_registers(start, buffer)
+    }
+
+    fn write_registers(
+        &mut self,
+        start: RegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::Error> {
+        (**self).write_registers(start, values)
+    }
+
+    fn read_register(&mut self, register: RegisterAddress) -> Result<u8, Self::Error> {
+        (**self).read_register(register)
+    }
+
+    fn write_register(&mut self, register: RegisterAddress, value: u8) -> Result<(), Self::Error> {
+        (**self).write_register(register, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusTransaction, Replay};
+
+    #[test]
+    fn read_temp_and_rate_uses_one_burst() {
+        let log = [BusTransaction::read(
+            RegisterAddress::OUT_TEMP,
+            &[0xFE, 0x0F, 1, 0, 2, 0, 0xFD, 0xFF],
+        )];
+        let mut bus = Replay::new(&log);
+        let (temp, rate) = bus.read_temp_and_rate().unwrap();
+        assert_eq!(temp, -2);
+        assert_eq!(rate, AngularRateRaw::new(1, 2, -3));
+    }
+}
@@ -0,0 +1,419 @@
+//! Calibration of angular rate readings.
+
+use crate::units::saturate_i16;
+use crate::{dps_to_raw, AngularRate, AngularRateRaw, Rounding, Sensitivity};
+
+/// Calibration data for correcting angular rate readings, e.g. from a rate table calibration.
+///
+/// A reading `ω` is corrected as `M · (ω - b)`, where `b` is the per-axis zero-rate bias in
+/// degrees per second and `M` is a 3×3 matrix combining the per-axis scale factors on its
+/// diagonal with the cross-axis sensitivity terms off the diagonal.
+///
+/// The [`Default`] profile applies no correction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CalibrationProfile {
+    bias: [f32; 3],
+    matrix: [[f32; 3]; 3],
+}
+
+impl CalibrationProfile {
+    /// The profile that applies no correction.
+    pub const IDENTITY: Self = Self {
+        bias: [0.0; 3],
+        matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    /// Creates a profile from the zero-rate bias in degrees per second and the
+    /// row-major correction matrix.
+    pub const fn new(bias: [f32; 3], matrix: [[f32; 3]; 3]) -> Self {
+        Self { bias, matrix }
+    }
+
+    /// Sets the zero-rate bias of the X, Y and Z axes in degrees per second.
+    pub const fn with_bias(mut self, bias: [f32; 3]) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    /// Sets the scale factors of the X, Y and Z axes, i.e. the diagonal of the correction
+    /// matrix, keeping the cross-axis terms.
+    pub const fn with_scale_factors(mut self, scale: [f32; 3]) -> Self {
+        self.matrix[0][0] = scale[0];
+        self.matrix[1][1] = scale[1];
+        self.matrix[2][2] = scale[2];
+        self
+    }
+
+    /// Sets the row-major correction matrix.
+    pub const fn with_matrix(mut self, matrix: [[f32; 3]; 3]) -> Self {
+        self.matrix = matrix;
+        self
+    }
+
+    /// Returns the zero-rate bias of the X, Y and Z axes in degrees per second.
+    pub const fn bias(&self) -> [f32; 3] {
+        self.bias
+    }
+
+    /// Returns the scale factors of the X, Y and Z axes.
+    pub const fn scale_factors(&self) -> [f32; 3] {
+        [self.matrix[0][0], self.matrix[1][1], self.matrix[2][2]]
+    }
+
+    /// Returns the row-major correction matrix.
+    pub const fn matrix(&self) -> [[f32; 3]; 3] {
+        self.matrix
+    }
+
+    /// The version of the binary format produced by [`to_bytes`](Self::to_bytes).
+    pub const FORMAT_VERSION: u8 = 1;
+
+    /// The length of the binary format produced by [`to_bytes`](Self::to_bytes).
+    pub const ENCODED_LEN: usize = 1 + 12 * 4;
+
+    /// Encodes the profile for persistent storage, e.g. in flash or EEPROM.
+    ///
+    /// The format consists of the [`FORMAT_VERSION`](Self::FORMAT_VERSION) byte followed by
+    /// the bias and the row-major matrix as little endian `f32` values.
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut bytes = [0; Self::ENCODED_LEN];
+        bytes[0] = Self::FORMAT_VERSION;
+        let values = self.bias.iter().chain(self.matrix.iter().flatten());
+        for (chunk, value) in bytes[1..].chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a profile encoded with [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CalibrationError> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(CalibrationError::InvalidLength {
+                expected: Self::ENCODED_LEN,
+                actual: bytes.len(),
+            });
+        }
+        if bytes[0] != Self::FORMAT_VERSION {
+            return Err(CalibrationError::UnsupportedVersion(bytes[0]));
+        }
+
+        let mut values = [0.0; 12];
+        for (value, chunk) in values.iter_mut().zip(bytes[1..].chunks_exact(4)) {
+            *value = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+
+        Ok(Self {
+            bias: [values[0], values[1], values[2]],
+            matrix: [
+                [values[3], values[4], values[5]],
+                [values[6], values[7], values[8]],
+                [values[9], values[10], values[11]],
+            ],
+        })
+    }
+
+    /// Corrects a reading.
+    #[must_use]
+    pub fn apply(&self, rate: AngularRate) -> AngularRate {
+        let v = [
+            rate.x() - self.bias[0],
+            rate.y() - self.bias[1],
+            rate.z() - self.bias[2],
+        ];
+        let [x, y, z] = self
+            .matrix
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2]);
+        AngularRate::from_dps(x, y, z)
+    }
+
+    /// Scales and corrects a raw reading.
+    #[must_use]
+    pub fn apply_raw(&self, raw: AngularRateRaw, sensitivity: Sensitivity) -> AngularRate {
+        self.apply(AngularRate::from_raw(raw, sensitivity))
+    }
+
+    /// Returns the correction matrix in fixed point, or `None` if it is the identity.
+    pub fn alignment(&self) -> Option<AlignmentMatrix> {
+        if self.matrix == Self::IDENTITY.matrix {
+            None
+        } else {
+            Some(AlignmentMatrix::from_f32(self.matrix))
+        }
+    }
+
+    /// Returns the bias in digits and the fixed-point correction matrix for correcting raw
+    /// readings without floating point arithmetic.
+    pub fn to_raw(&self, sensitivity: Sensitivity) -> RawCalibration {
+        RawCalibration {
+            bias: self
+                .bias
+                .map(|bias| dps_to_raw(bias, sensitivity, Rounding::Nearest)),
+            alignment: self.alignment(),
+        }
+    }
+}
+
+impl Default for CalibrationProfile {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A 3×3 alignment matrix in fixed point, correcting scale factors and cross-axis
+/// sensitivity of raw readings.
+///
+/// Elements have [`FRACTIONAL_BITS`](Self::FRACTIONAL_BITS) fractional bits, i.e.
+/// [`ONE`](Self::ONE) represents `1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlignmentMatrix {
+    rows: [[i32; 3]; 3],
+}
+
+impl AlignmentMatrix {
+    /// The number of fractional bits of each element.
+    pub const FRACTIONAL_BITS: u32 = 16;
+
+    /// The fixed-point representation of `1.0`.
+    pub const ONE: i32 = 1 << Self::FRACTIONAL_BITS;
+
+    /// The matrix that leaves readings unchanged.
+    pub const IDENTITY: Self = Self {
+        rows: [[Self::ONE, 0, 0], [0, Self::ONE, 0], [0, 0, Self::ONE]],
+    };
+
+    /// Creates a matrix from row-major fixed-point elements.
+    pub const fn from_fixed(rows: [[i32; 3]; 3]) -> Self {
+        Self { rows }
+    }
+
+    /// Converts a row-major matrix, rounding each element to the nearest fixed-point value.
+    pub fn from_f32(matrix: [[f32; 3]; 3]) -> Self {
+        Self {
+            rows: matrix
+                .map(|row| row.map(|value| Rounding::Nearest.round(value * Self::ONE as f32))),
+        }
+    }
+
+    /// Returns the row-major fixed-point elements.
+    pub const fn to_fixed(&self) -> [[i32; 3]; 3] {
+        self.rows
+    }
+
+    /// Returns the row-major matrix.
+    pub fn to_f32(&self) -> [[f32; 3]; 3] {
+        self.rows
+            .map(|row| row.map(|value| value as f32 / Self::ONE as f32))
+    }
+
+    /// Multiplies a reading by the matrix, rounding to the nearest digit and saturating at
+    /// the `i16` range.
+    #[must_use]
+    pub const fn apply(&self, raw: AngularRateRaw) -> AngularRateRaw {
+        AngularRateRaw::new(
+            self.apply_row(0, raw),
+            self.apply_row(1, raw),
+            self.apply_row(2, raw),
+        )
+    }
+
+    /// Multiplies a scaled reading by the matrix.
+    #[must_use]
+    pub fn apply_rate(&self, rate: AngularRate) -> AngularRate {
+        let v = rate.to_dps();
+        let [x, y, z] = self
+            .to_f32()
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2]);
+        AngularRate::from_dps(x, y, z)
+    }
+
+    const fn apply_row(&self, row: usize, raw: AngularRateRaw) -> i16 {
+        let [a, b, c] = self.rows[row];
+        let sum = a as i64 * raw.x as i64 + b as i64 * raw.y as i64 + c as i64 * raw.z as i64;
+        saturate_i16(Rounding::Nearest.div(sum, Self::ONE as i64))
+    }
+}
+
+impl Default for AlignmentMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Integer correction of raw readings: bias removal followed by an optional
+/// [`AlignmentMatrix`].
+///
+/// Derive it from a [`CalibrationProfile`] using [`CalibrationProfile::to_raw`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RawCalibration {
+    /// The zero-rate bias of the X, Y and Z axes in digits.
+    pub bias: [i16; 3],
+    /// The alignment applied after removing the bias, if any.
+    pub alignment: Option<AlignmentMatrix>,
+}
+
+impl RawCalibration {
+    /// Creates a correction from the bias in digits and an optional alignment.
+    pub const fn new(bias: [i16; 3], alignment: Option<AlignmentMatrix>) -> Self {
+        Self { bias, alignment }
+    }
+
+    /// Corrects a raw reading. Intermediate results saturate at the `i16` range.
+    #[must_use]
+    pub const fn apply(&self, raw: AngularRateRaw) -> AngularRateRaw {
+        let unbiased = AngularRateRaw::new(
+            raw.x.saturating_sub(self.bias[0]),
+            raw.y.saturating_sub(self.bias[1]),
+            raw.z.saturating_sub(self.bias[2]),
+        );
+        match &self.alignment {
+            Some(alignment) => alignment.apply(unbiased),
+            None => unbiased,
+        }
+    }
+}
+
+/// An error when decoding a [`CalibrationProfile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationError {
+    /// The data does not have the required number of bytes.
+    InvalidLength {
+        /// The number of bytes required.
+        expected: usize,
+        /// The number of bytes provided.
+        actual: usize,
+    },
+    /// The data was encoded in an unsupported format version.
+    UnsupportedVersion(u8),
+}
+
+impl core::fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CalibrationError::InvalidLength { expected, actual } => write!(
+                f,
+                "calibration data has {actual} bytes, but {expected} bytes are required"
+            ),
+            CalibrationError::UnsupportedVersion(version) => {
+                write!(f, "unsupported calibration format version {version}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for CalibrationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_leaves_readings_unchanged() {
+        let rate = AngularRate::from_dps(1.5, -2.0, 300.0);
+        assert_eq!(CalibrationProfile::default().apply(rate), rate);
+        assert_eq!(CalibrationProfile::IDENTITY.alignment(), None);
+    }
+
+    #[test]
+    fn removes_bias_before_scaling() {
+        let profile = CalibrationProfile::IDENTITY
+            .with_bias([1.0, 2.0, 3.0])
+            .with_scale_factors([2.0, 1.0, 0.5]);
+        let corrected = profile.apply(AngularRate::from_dps(10.0, 20.0, 30.0));
+        assert_eq!(corrected.to_dps(), [18.0, 18.0, 13.5]);
+        assert_eq!(profile.scale_factors(), [2.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn applies_cross_axis_terms() {
+        let profile = CalibrationProfile::IDENTITY
+            .with_matrix([[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [-0.25, 0.0, 1.0]])
+            .with_scale_factors([2.0, 1.0, 1.0]);
+        assert_eq!(profile.matrix()[0], [2.0, 0.5, 0.0]);
+        let corrected = profile.apply(AngularRate::from_dps(4.0, 8.0, 1.0));
+        assert_eq!(corrected.to_dps(), [12.0, 8.0, 0.0]);
+    }
+
+    #[test]
+    fn alignment_matrix_rounds_to_nearest() {
+        let half = AlignmentMatrix::ONE / 2;
+        let matrix =
+            AlignmentMatrix::from_f32([[0.5, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -0.5]]);
+        assert_eq!(matrix.to_fixed()[0], [half, 0, 0]);
+        assert_eq!(matrix.to_f32()[2], [0.0, 0.0, -0.5]);
+        assert_eq!(
+            matrix.apply(AngularRateRaw::new(3, -7, 3)),
+            AngularRateRaw::new(2, -7, -2)
+        );
+    }
+
+    #[test]
+    fn alignment_matrix_saturates() {
+        let double = AlignmentMatrix::from_fixed([
+            [2 * AlignmentMatrix::ONE, 0, 0],
+            [0, 2 * AlignmentMatrix::ONE, 0],
+            [0, 0, AlignmentMatrix::ONE],
+        ]);
+        assert_eq!(
+            double.apply(AngularRateRaw::new(i16::MAX, i16::MIN, 5)),
+            AngularRateRaw::new(i16::MAX, i16::MIN, 5)
+        );
+        assert_eq!(
+            AlignmentMatrix::default().apply(AngularRateRaw::new(1, 2, 3)),
+            AngularRateRaw::new(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn raw_calibration_matches_profile() {
+        let profile = CalibrationProfile::IDENTITY
+            .with_bias([8.75, -17.5, 0.0])
+            .with_scale_factors([1.0, 2.0, 1.0]);
+        let raw = profile.to_raw(Sensitivity::D250);
+        assert_eq!(raw.bias, [1000, -2000, 0]);
+        assert!(raw.alignment.is_some());
+        assert_eq!(
+            raw.apply(AngularRateRaw::new(1100, -1900, 7)),
+            AngularRateRaw::new(100, 200, 7)
+        );
+        assert_eq!(
+            RawCalibration::new([1, 0, 0], None).apply(AngularRateRaw::new(i16::MIN, 0, 0)),
+            AngularRateRaw::new(i16::MIN, 0, 0)
+        );
+    }
+
+    #[test]
+    fn encoding_round_trips() {
+        let profile = CalibrationProfile::new(
+            [0.25, -1.5, 3.0],
+            [[1.01, 0.02, -0.03], [0.0, 0.99, 0.0], [-0.5, 0.125, 1.0]],
+        );
+        let bytes = profile.to_bytes();
+        assert_eq!(bytes.len(), CalibrationProfile::ENCODED_LEN);
+        assert_eq!(bytes[0], CalibrationProfile::FORMAT_VERSION);
+        assert_eq!(bytes[1..5], 0.25f32.to_le_bytes());
+        assert_eq!(CalibrationProfile::from_bytes(&bytes), Ok(profile));
+    }
+
+    #[test]
+    fn decoding_rejects_invalid_data() {
+        let mut bytes = CalibrationProfile::IDENTITY.to_bytes();
+        assert_eq!(
+            CalibrationProfile::from_bytes(&bytes[..10]),
+            Err(CalibrationError::InvalidLength {
+                expected: CalibrationProfile::ENCODED_LEN,
+                actual: 10,
+            })
+        );
+
+        bytes[0] = 2;
+        assert_eq!(
+            CalibrationProfile::from_bytes(&bytes),
+            Err(CalibrationError::UnsupportedVersion(2))
+        );
+    }
+}
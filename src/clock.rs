@@ -0,0 +1,122 @@
+//! Timestamping of measurements.
+
+use crate::{Measurement, OutputDataRate};
+
+/// A monotonic clock providing timestamps in microseconds.
+///
+/// Closures returning the current time are clocks as well, e.g. `|| timer.now().ticks()`.
+pub trait Clock {
+    /// Returns the current time in microseconds since an arbitrary epoch.
+    fn now_us(&mut self) -> u64;
+
+    /// Timestamps a measurement with the current time.
+    fn stamp<T>(&mut self, measurement: T) -> TimestampedMeasurement<T> {
+        TimestampedMeasurement::new(self.now_us(), measurement)
+    }
+}
+
+impl<F> Clock for F
+where
+    F: FnMut() -> u64,
+{
+    fn now_us(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// A measurement along with the time it was taken.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimestampedMeasurement<T = Measurement> {
+    /// The time in microseconds, see [`Clock`].
+    pub timestamp_us: u64,
+    /// The measurement.
+    pub measurement: T,
+}
+
+impl<T> TimestampedMeasurement<T> {
+    /// Creates a new timestamped measurement.
+    pub const fn new(timestamp_us: u64, measurement: T) -> Self {
+        Self {
+            timestamp_us,
+            measurement,
+        }
+    }
+
+    /// Transforms the measurement, keeping the timestamp.
+    pub fn map<U, F>(self, f: F) -> TimestampedMeasurement<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        TimestampedMeasurement::new(self.timestamp_us, f(self.measurement))
+    }
+
+    /// Returns the time elapsed since an earlier measurement in microseconds,
+    /// or zero if `earlier` is not actually earlier.
+    pub const fn elapsed_since<U>(&self, earlier: &TimestampedMeasurement<U>) -> u64 {
+        self.timestamp_us.saturating_sub(earlier.timestamp_us)
+    }
+}
+
+/// Estimates the sampling time of a frame read from the FIFO.
+///
+/// The FIFO holds `count` frames sampled at the output data rate, with the last frame
+/// (`index == count - 1`) being the most recent one, taken at about `read_us`. Earlier
+/// frames are backdated by one sample period each.
+pub const fn fifo_frame_timestamp_us(
+    read_us: u64,
+    index: usize,
+    count: usize,
+    odr: OutputDataRate,
+) -> u64 {
+    let age = count.saturating_sub(index + 1) as u64;
+    read_us.saturating_sub(age * 1_000_000 / odr.hz() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closures_stamp_measurements() {
+        let mut now_us = 0;
+        let mut clock = || {
+            now_us += 250;
+            now_us
+        };
+        let first = clock.stamp(1u8);
+        let second = clock.stamp(2u8).map(u16::from);
+        assert_eq!(first, TimestampedMeasurement::new(250, 1));
+        assert_eq!(second, TimestampedMeasurement::new(500, 2));
+        assert_eq!(second.elapsed_since(&first), 250);
+        assert_eq!(first.elapsed_since(&second), 0);
+    }
+
+    #[test]
+    fn fifo_frames_are_backdated() {
+        let stamp = |index| fifo_frame_timestamp_us(100_000, index, 3, OutputDataRate::Hz95);
+        assert_eq!(stamp(2), 100_000);
+        assert_eq!(stamp(1), 100_000 - 10_526);
+        assert_eq!(stamp(0), 100_000 - 21_052);
+        assert_eq!(
+            fifo_frame_timestamp_us(100_000, 30, 32, OutputDataRate::Hz760),
+            100_000 - 1_315
+        );
+    }
+
+    #[test]
+    fn fifo_frame_timestamps_saturate() {
+        assert_eq!(
+            fifo_frame_timestamp_us(5_000, 0, 32, OutputDataRate::Hz95),
+            0
+        );
+        assert_eq!(
+            fifo_frame_timestamp_us(5_000, 3, 3, OutputDataRate::Hz95),
+            5_000
+        );
+        assert_eq!(
+            fifo_frame_timestamp_us(5_000, 0, 0, OutputDataRate::Hz95),
+            5_000
+        );
+    }
+}
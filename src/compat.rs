@@ -0,0 +1,197 @@
+//! Types mirroring the [`l3gd20`](https://crates.io/crates/l3gd20) crate.
+//!
+//! These types ease the migration of existing projects, e.g. on the STM32F3DISCOVERY board,
+//! by providing the familiar names along with [`From`] conversions to and from the types of
+//! this crate.
+
+use crate::{AngularRateRaw, Measurement, OutputDataRate, Sensitivity, StatusRegister};
+
+/// XYZ triple.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct I16x3 {
+    /// X component.
+    pub x: i16,
+    /// Y component.
+    pub y: i16,
+    /// Z component.
+    pub z: i16,
+}
+
+/// Several measurements.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Measurements {
+    /// Gyroscope measurements.
+    pub gyro: I16x3,
+    /// Temperature sensor measurement.
+    pub temp: u8,
+}
+
+/// Sensor status.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Status {
+    /// Overrun (data has overwritten previously unread data)
+    /// has occurred on at least one axis.
+    pub overrun: bool,
+    /// New data is available on all three axes.
+    pub new_data: bool,
+}
+
+/// Output data rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Odr {
+    /// 95 Hz data rate.
+    Hz95 = 0x00,
+    /// 190 Hz data rate.
+    Hz190 = 0x01,
+    /// 380 Hz data rate.
+    Hz380 = 0x02,
+    /// 760 Hz data rate.
+    Hz760 = 0x03,
+}
+
+/// Full scale selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Scale {
+    /// 250 Degrees Per Second.
+    Dps250 = 0x00,
+    /// 500 Degrees Per Second.
+    Dps500 = 0x01,
+    /// 2000 Degrees Per Second.
+    Dps2000 = 0x03,
+}
+
+impl Scale {
+    /// Converts a raw reading into degrees per second.
+    #[must_use]
+    pub fn degrees(&self, value: i16) -> f32 {
+        Sensitivity::from(*self).mdps_per_digit() / 1000.0 * value as f32
+    }
+
+    /// Converts a raw reading into radians per second.
+    #[must_use]
+    pub fn radians(&self, value: i16) -> f32 {
+        self.degrees(value) * (core::f32::consts::PI / 180.0)
+    }
+}
+
+/// Bandwidth of sensor.
+///
+/// The exact cutoff frequency depends on the output data rate; see [`crate::Bandwidth`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Bandwidth {
+    /// Lowest possible bandwidth.
+    Low = 0x00,
+    /// Medium bandwidth.
+    Medium = 0x01,
+    /// High bandwidth.
+    High = 0x02,
+    /// Maximum bandwidth.
+    Maximum = 0x03,
+}
+
+impl From<AngularRateRaw> for I16x3 {
+    fn from(value: AngularRateRaw) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<I16x3> for AngularRateRaw {
+    fn from(value: I16x3) -> Self {
+        Self::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<Measurement> for Measurements {
+    fn from(value: Measurement) -> Self {
+        Self {
+            gyro: value.rate.into(),
+            temp: value.temp as u8,
+        }
+    }
+}
+
+impl From<StatusRegister> for Status {
+    fn from(value: StatusRegister) -> Self {
+        Self {
+            overrun: value.zyx_overrun(),
+            new_data: value.zyx_da(),
+        }
+    }
+}
+
+impl From<Odr> for OutputDataRate {
+    fn from(value: Odr) -> Self {
+        match value {
+            Odr::Hz95 => OutputDataRate::Hz95,
+            Odr::Hz190 => OutputDataRate::Hz190,
+            Odr::Hz380 => OutputDataRate::Hz380,
+            Odr::Hz760 => OutputDataRate::Hz760,
+        }
+    }
+}
+
+impl From<OutputDataRate> for Odr {
+    fn from(value: OutputDataRate) -> Self {
+        match value {
+            OutputDataRate::Hz95 => Odr::Hz95,
+            OutputDataRate::Hz190 => Odr::Hz190,
+            OutputDataRate::Hz380 => Odr::Hz380,
+            OutputDataRate::Hz760 => Odr::Hz760,
+        }
+    }
+}
+
+impl From<Scale> for Sensitivity {
+    fn from(value: Scale) -> Self {
+        match value {
+            Scale::Dps250 => Sensitivity::D250,
+            Scale::Dps500 => Sensitivity::D500,
+            Scale::Dps2000 => Sensitivity::D2000,
+        }
+    }
+}
+
+impl From<Sensitivity> for Scale {
+    fn from(value: Sensitivity) -> Self {
+        match value {
+            Sensitivity::D250 => Scale::Dps250,
+            Sensitivity::D500 => Scale::Dps500,
+            Sensitivity::D2000 | Sensitivity::D2000_11 => Scale::Dps2000,
+        }
+    }
+}
+
+impl From<Bandwidth> for crate::Bandwidth {
+    fn from(value: Bandwidth) -> Self {
+        match value {
+            Bandwidth::Low => crate::Bandwidth::Narrowest,
+            Bandwidth::Medium => crate::Bandwidth::Narrow,
+            Bandwidth::High => crate::Bandwidth::Medium,
+            Bandwidth::Maximum => crate::Bandwidth::Wide,
+        }
+    }
+}
+
+impl From<crate::Bandwidth> for Bandwidth {
+    fn from(value: crate::Bandwidth) -> Self {
+        match value {
+            crate::Bandwidth::Narrowest => Bandwidth::Low,
+            crate::Bandwidth::Narrow => Bandwidth::Medium,
+            crate::Bandwidth::Medium => Bandwidth::High,
+            crate::Bandwidth::Wide => Bandwidth::Maximum,
+        }
+    }
+}
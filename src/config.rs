@@ -0,0 +1,512 @@
+//! Complete sensor configurations.
+
+use crate::{
+    ControlRegister1, ControlRegister2, ControlRegister3, ControlRegister4, ControlRegister5,
+    DrdyConfig, FifoControlRegister, FifoMode, Int1ConfigurationRegister, Int1DurationRegister,
+    Int1ThresholdRegisterXH, Int1ThresholdRegisterXL, Int1ThresholdRegisterYH,
+    Int1ThresholdRegisterYL, Int1ThresholdRegisterZH, Int1ThresholdRegisterZL, PowerMode,
+    ReferenceRegister, RegisterAddress, RegisterFile,
+};
+
+/// The values of all writable registers, i.e. a complete sensor configuration.
+///
+/// The [`Default`] configuration corresponds to the register reset values.
+///
+/// All constructors are `const fn`, so that configurations can be stored as constants:
+///
+/// ```
+/// # use l3gd20_registers::*;
+/// const CONFIG: Config = Config::new()
+///     .with_ctrl_reg1(ControlRegister1::new().with_power_up(true))
+///     .with_ctrl_reg4(ControlRegister4::new().with_full_scale(Sensitivity::D500));
+/// const CONTROL: [u8; 5] = CONFIG.control_bytes();
+/// assert_eq!(CONTROL, [0x0F, 0x00, 0x00, 0x10, 0x00]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub struct Config {
+    pub ctrl_reg1: ControlRegister1,
+    pub ctrl_reg2: ControlRegister2,
+    pub ctrl_reg3: ControlRegister3,
+    pub ctrl_reg4: ControlRegister4,
+    pub ctrl_reg5: ControlRegister5,
+    pub reference: ReferenceRegister,
+    pub fifo_ctrl: FifoControlRegister,
+    pub int1_cfg: Int1ConfigurationRegister,
+    pub int1_tsh_xh: Int1ThresholdRegisterXH,
+    pub int1_tsh_xl: Int1ThresholdRegisterXL,
+    pub int1_tsh_yh: Int1ThresholdRegisterYH,
+    pub int1_tsh_yl: Int1ThresholdRegisterYL,
+    pub int1_tsh_zh: Int1ThresholdRegisterZH,
+    pub int1_tsh_zl: Int1ThresholdRegisterZL,
+    pub int1_duration: Int1DurationRegister,
+}
+
+impl Config {
+    /// Creates the configuration corresponding to the register reset values.
+    pub const fn new() -> Self {
+        Self {
+            ctrl_reg1: ControlRegister1::new(),
+            ctrl_reg2: ControlRegister2::new(),
+            ctrl_reg3: ControlRegister3::new(),
+            ctrl_reg4: ControlRegister4::new(),
+            ctrl_reg5: ControlRegister5::new(),
+            reference: ReferenceRegister::new(),
+            fifo_ctrl: FifoControlRegister::new(),
+            int1_cfg: Int1ConfigurationRegister::new(),
+            int1_tsh_xh: Int1ThresholdRegisterXH::new(),
+            int1_tsh_xl: Int1ThresholdRegisterXL::new(),
+            int1_tsh_yh: Int1ThresholdRegisterYH::new(),
+            int1_tsh_yl: Int1ThresholdRegisterYL::new(),
+            int1_tsh_zh: Int1ThresholdRegisterZH::new(),
+            int1_tsh_zl: Int1ThresholdRegisterZL::new(),
+            int1_duration: Int1DurationRegister::new(),
+        }
+    }
+
+    /// Sets [`CTRL_REG1`](crate::RegisterAddress::CTRL_REG1).
+    #[must_use]
+    pub const fn with_ctrl_reg1(mut self, value: ControlRegister1) -> Self {
+        self.ctrl_reg1 = value;
+        self
+    }
+
+    /// Sets [`CTRL_REG2`](crate::RegisterAddress::CTRL_REG2).
+    #[must_use]
+    pub const fn with_ctrl_reg2(mut self, value: ControlRegister2) -> Self {
+        self.ctrl_reg2 = value;
+        self
+    }
+
+    /// Sets [`CTRL_REG3`](crate::RegisterAddress::CTRL_REG3).
+    #[must_use]
+    pub const fn with_ctrl_reg3(mut self, value: ControlRegister3) -> Self {
+        self.ctrl_reg3 = value;
+        self
+    }
+
+    /// Sets [`CTRL_REG4`](crate::RegisterAddress::CTRL_REG4).
+    #[must_use]
+    pub const fn with_ctrl_reg4(mut self, value: ControlRegister4) -> Self {
+        self.ctrl_reg4 = value;
+        self
+    }
+
+    /// Sets [`CTRL_REG5`](crate::RegisterAddress::CTRL_REG5).
+    #[must_use]
+    pub const fn with_ctrl_reg5(mut self, value: ControlRegister5) -> Self {
+        self.ctrl_reg5 = value;
+        self
+    }
+
+    /// Sets [`REFERENCE`](crate::RegisterAddress::REFERENCE).
+    #[must_use]
+    pub const fn with_reference(mut self, value: ReferenceRegister) -> Self {
+        self.reference = value;
+        self
+    }
+
+    /// Sets [`FIFO_CTRL_REG`](crate::RegisterAddress::FIFO_CTRL_REG).
+    #[must_use]
+    pub const fn with_fifo_ctrl(mut self, value: FifoControlRegister) -> Self {
+        self.fifo_ctrl = value;
+        self
+    }
+
+    /// Sets [`INT1_CFG`](crate::RegisterAddress::INT1_CFG).
+    #[must_use]
+    pub const fn with_int1_cfg(mut self, value: Int1ConfigurationRegister) -> Self {
+        self.int1_cfg = value;
+        self
+    }
+
+    /// Sets [`INT1_TSH_XH`](crate::RegisterAddress::INT1_TSH_XH).
+    #[must_use]
+    pub const fn with_int1_tsh_xh(mut self, value: Int1ThresholdRegisterXH) -> Self {
+        self.int1_tsh_xh = value;
+        self
+    }
+
+    /// Sets [`INT1_TSH_XL`](crate::RegisterAddress::INT1_TSH_XL).
+    #[must_use]
+    pub const fn with_int1_tsh_xl(mut self, value: Int1ThresholdRegisterXL) -> Self {
+        self.int1_tsh_xl = value;
+        self
+    }
+
+    /// Sets [`INT1_TSH_YH`](crate::RegisterAddress::INT1_TSH_YH).
+    #[must_use]
+    pub const fn with_int1_tsh_yh(mut self, value: Int1ThresholdRegisterYH) -> Self {
+        self.int1_tsh_yh = value;
+        self
+    }
+
+    /// Sets [`INT1_TSH_YL`](crate::RegisterAddress::INT1_TSH_YL).
+    #[must_use]
+    pub const fn with_int1_tsh_yl(mut self, value: Int1ThresholdRegisterYL) -> Self {
+        self.int1_tsh_yl = value;
+        self
+    }
+
+    /// Sets [`INT1_TSH_ZH`](crate::RegisterAddress::INT1_TSH_ZH).
+    #[must_use]
+    pub const fn with_int1_tsh_zh(mut self, value: Int1ThresholdRegisterZH) -> Self {
+        self.int1_tsh_zh = value;
+        self
+    }
+
+    /// Sets [`INT1_TSH_ZL`](crate::RegisterAddress::INT1_TSH_ZL).
+    #[must_use]
+    pub const fn with_int1_tsh_zl(mut self, value: Int1ThresholdRegisterZL) -> Self {
+        self.int1_tsh_zl = value;
+        self
+    }
+
+    /// Sets [`INT1_DURATION`](crate::RegisterAddress::INT1_DURATION).
+    #[must_use]
+    pub const fn with_int1_duration(mut self, value: Int1DurationRegister) -> Self {
+        self.int1_duration = value;
+        self
+    }
+
+    /// Returns the values of `CTRL_REG1` (20h) through `CTRL_REG5` (24h) in address order,
+    /// e.g. for a single auto-increment write.
+    pub const fn control_bytes(&self) -> [u8; 5] {
+        [
+            self.ctrl_reg1.into_bits(),
+            self.ctrl_reg2.into_bits(),
+            self.ctrl_reg3.into_bits(),
+            self.ctrl_reg4.into_bits(),
+            self.ctrl_reg5.into_bits(),
+        ]
+    }
+
+    /// Returns the register writes that apply the configuration, in a safe order.
+    ///
+    /// [`CTRL_REG1`](RegisterAddress::CTRL_REG1) is written last so that the sensor only leaves
+    /// power-down mode once all other registers are configured.
+    pub const fn write_sequence(&self) -> [(RegisterAddress, u8); 15] {
+        [
+            (RegisterAddress::CTRL_REG2, self.ctrl_reg2.into_bits()),
+            (RegisterAddress::CTRL_REG3, self.ctrl_reg3.into_bits()),
+            (RegisterAddress::CTRL_REG4, self.ctrl_reg4.into_bits()),
+            (RegisterAddress::CTRL_REG5, self.ctrl_reg5.into_bits()),
+            (RegisterAddress::REFERENCE, self.reference.into_bits()),
+            (RegisterAddress::FIFO_CTRL_REG, self.fifo_ctrl.into_bits()),
+            (RegisterAddress::INT1_CFG, self.int1_cfg.into_bits()),
+            (RegisterAddress::INT1_TSH_XH, self.int1_tsh_xh.into_bits()),
+            (RegisterAddress::INT1_TSH_XL, self.int1_tsh_xl.into_bits()),
+            (RegisterAddress::INT1_TSH_YH, self.int1_tsh_yh.into_bits()),
+            (RegisterAddress::INT1_TSH_YL, self.int1_tsh_yl.into_bits()),
+            (RegisterAddress::INT1_TSH_ZH, self.int1_tsh_zh.into_bits()),
+            (RegisterAddress::INT1_TSH_ZL, self.int1_tsh_zl.into_bits()),
+            (
+                RegisterAddress::INT1_DURATION,
+                self.int1_duration.into_bits(),
+            ),
+            (RegisterAddress::CTRL_REG1, self.ctrl_reg1.into_bits()),
+        ]
+    }
+
+    /// Returns the register write that reboots the memory content, keeping the other bits
+    /// of [`CTRL_REG5`](RegisterAddress::CTRL_REG5).
+    ///
+    /// To recover from a brown-out or register corruption, perform this write, wait for
+    /// [`Transition::Reboot`](crate::Transition::Reboot) to settle and replay the
+    /// [`write_sequence`](Self::write_sequence).
+    pub const fn reboot_write(&self) -> (RegisterAddress, u8) {
+        (
+            RegisterAddress::CTRL_REG5,
+            self.ctrl_reg5.with_boot(true).into_bits(),
+        )
+    }
+
+    /// Returns the signals routed to the DRDY/INT2 pin.
+    pub const fn drdy(&self) -> DrdyConfig {
+        DrdyConfig::from_register(self.ctrl_reg3)
+    }
+
+    /// Sets the signals routed to the DRDY/INT2 pin, keeping the INT1 configuration.
+    #[must_use]
+    pub const fn with_drdy(mut self, drdy: DrdyConfig) -> Self {
+        self.ctrl_reg3 = drdy.apply(self.ctrl_reg3);
+        self
+    }
+
+    /// Checks the configuration for suspicious register combinations.
+    ///
+    /// The sensor accepts all of these combinations, but they usually indicate a mistake.
+    pub fn lint(&self) -> Lints {
+        let mut lints = Lints::new();
+
+        let drdy = self.drdy();
+        if drdy.has_fifo_interrupts() && !self.ctrl_reg5.fifo_enable() {
+            lints.insert(ConfigWarning::FifoInterruptsWithoutFifo);
+        }
+
+        let bypass = self.fifo_ctrl.try_fifo_mode() == Some(FifoMode::Bypass);
+        if self.ctrl_reg5.fifo_enable() && bypass {
+            lints.insert(ConfigWarning::FifoEnabledInBypassMode);
+        }
+
+        if self.fifo_ctrl.watermark() != 0 && bypass {
+            lints.insert(ConfigWarning::WatermarkInBypassMode);
+        }
+
+        if !self.ctrl_reg5.hpen() && self.ctrl_reg5.out_sel() & 0b10 != 0 {
+            lints.insert(ConfigWarning::HighpassOutputWithoutHighpass);
+        }
+
+        if !self.ctrl_reg5.hpen() && self.ctrl_reg5.int1_sel() & 0b10 != 0 {
+            lints.insert(ConfigWarning::HighpassInterruptWithoutHighpass);
+        }
+
+        if self.power_mode() == PowerMode::Sleep && self.ctrl_reg3.i2drdy() {
+            lints.insert(ConfigWarning::SleepModeWithDataReady);
+        }
+
+        lints.extend(drdy.lint());
+
+        let int1_events = self.int1_cfg.into_bits() & 0b0011_1111 != 0;
+        if int1_events && !self.ctrl_reg3.i1int1() {
+            lints.insert(ConfigWarning::Int1EventsNotRouted);
+        }
+
+        lints
+    }
+
+    /// Checks the dependencies between registers that would render the configuration
+    /// ineffective.
+    ///
+    /// Unlike [`lint`](Self::lint), this only reports combinations that cannot work as intended.
+    /// Call it before applying the [`write_sequence`](Self::write_sequence) or use
+    /// [`checked_write_sequence`](Self::checked_write_sequence).
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let watermark = self.fifo_ctrl.watermark() != 0 || self.ctrl_reg3.i2wtm();
+        if watermark && !self.ctrl_reg5.fifo_enable() {
+            return Err(ConfigError::WatermarkWithoutFifo);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the configuration and returns the register writes that apply it.
+    ///
+    /// See [`validate`](Self::validate) and [`write_sequence`](Self::write_sequence).
+    pub fn checked_write_sequence(&self) -> Result<[(RegisterAddress, u8); 15], ConfigError> {
+        self.validate()?;
+        Ok(self.write_sequence())
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<RegisterFile> for Config {
+    fn from(value: RegisterFile) -> Self {
+        Self {
+            ctrl_reg1: value.ctrl_reg1,
+            ctrl_reg2: value.ctrl_reg2,
+            ctrl_reg3: value.ctrl_reg3,
+            ctrl_reg4: value.ctrl_reg4,
+            ctrl_reg5: value.ctrl_reg5,
+            reference: value.reference,
+            fifo_ctrl: value.fifo_ctrl,
+            int1_cfg: value.int1_cfg,
+            int1_tsh_xh: value.int1_tsh_xh,
+            int1_tsh_xl: value.int1_tsh_xl,
+            int1_tsh_yh: value.int1_tsh_yh,
+            int1_tsh_yl: value.int1_tsh_yl,
+            int1_tsh_zh: value.int1_tsh_zh,
+            int1_tsh_zl: value.int1_tsh_zl,
+            int1_duration: value.int1_duration,
+        }
+    }
+}
+
+/// An inconsistent register combination found by [`Config::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    /// A FIFO watermark is configured or routed to DRDY/INT2, but the FIFO is disabled.
+    WatermarkWithoutFifo,
+}
+
+impl core::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfigError::WatermarkWithoutFifo => {
+                f.write_str("FIFO watermark is configured, but the FIFO is disabled")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ConfigError {}
+
+/// A suspicious register combination found by [`Config::lint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum ConfigWarning {
+    /// FIFO interrupts are routed to DRDY/INT2, but the FIFO is disabled.
+    FifoInterruptsWithoutFifo = 0,
+    /// The FIFO is enabled, but operates in [`FifoMode::Bypass`].
+    FifoEnabledInBypassMode = 1,
+    /// A FIFO watermark is set, but the FIFO operates in [`FifoMode::Bypass`].
+    WatermarkInBypassMode = 2,
+    /// The high-pass and second low-pass filter path (`OUT_SEL = 1x`) is selected for the
+    /// output data, but the high-pass filter is disabled, so the data is only low-pass
+    /// filtered. The `01` selection uses the high-pass filter regardless of `HPEN`.
+    HighpassOutputWithoutHighpass = 3,
+    /// The high-pass and second low-pass filter path (`INT1_SEL = 1x`) is selected for the
+    /// interrupt generator, but the high-pass filter is disabled, so the data is only low-pass
+    /// filtered. The `01` selection uses the high-pass filter regardless of `HPEN`.
+    HighpassInterruptWithoutHighpass = 4,
+    /// The sensor is in sleep mode (all axes disabled), but data-ready is routed to DRDY/INT2.
+    /// No data will become ready.
+    SleepModeWithDataReady = 5,
+    /// Both data-ready and FIFO watermark interrupts are routed to DRDY/INT2 and
+    /// cannot be told apart.
+    DataReadyAndWatermarkShared = 6,
+    /// INT1 events are enabled in `INT1_CFG`, but the interrupt is not routed to the INT1 pin.
+    Int1EventsNotRouted = 7,
+    /// Data-ready and FIFO overrun or empty interrupts are routed to DRDY/INT2 and
+    /// cannot be told apart.
+    DataReadyAndFifoStatusShared = 8,
+}
+
+impl ConfigWarning {
+    const ALL: [ConfigWarning; 9] = [
+        ConfigWarning::FifoInterruptsWithoutFifo,
+        ConfigWarning::FifoEnabledInBypassMode,
+        ConfigWarning::WatermarkInBypassMode,
+        ConfigWarning::HighpassOutputWithoutHighpass,
+        ConfigWarning::HighpassInterruptWithoutHighpass,
+        ConfigWarning::SleepModeWithDataReady,
+        ConfigWarning::DataReadyAndWatermarkShared,
+        ConfigWarning::Int1EventsNotRouted,
+        ConfigWarning::DataReadyAndFifoStatusShared,
+    ];
+
+    const fn mask(self) -> u16 {
+        1 << (self as u8)
+    }
+}
+
+/// A set of [`ConfigWarning`]s as returned by [`Config::lint`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lints(u16);
+
+impl Lints {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Adds a warning to the set.
+    pub fn insert(&mut self, warning: ConfigWarning) {
+        self.0 |= warning.mask();
+    }
+
+    /// Adds all warnings of another set.
+    pub fn extend(&mut self, other: Lints) {
+        self.0 |= other.0;
+    }
+
+    /// Determines whether the set contains the specified warning.
+    pub const fn contains(&self, warning: ConfigWarning) -> bool {
+        self.0 & warning.mask() != 0
+    }
+
+    /// Determines whether no warnings were found.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of warnings.
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Iterates the warnings in the set.
+    pub const fn iter(&self) -> LintsIter {
+        LintsIter {
+            lints: *self,
+            index: 0,
+        }
+    }
+}
+
+impl IntoIterator for Lints {
+    type Item = ConfigWarning;
+    type IntoIter = LintsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over [`Lints`].
+#[derive(Debug, Clone)]
+pub struct LintsIter {
+    lints: Lints,
+    index: usize,
+}
+
+impl Iterator for LintsIter {
+    type Item = ConfigWarning;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(warning) = ConfigWarning::ALL.get(self.index) {
+            self.index += 1;
+            if self.lints.contains(*warning) {
+                return Some(*warning);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_watermark_without_fifo() {
+        let watermark = Config::new().with_fifo_ctrl(FifoControlRegister::new().with_watermark(4));
+        assert_eq!(watermark.validate(), Err(ConfigError::WatermarkWithoutFifo));
+
+        let routed = Config::new().with_ctrl_reg3(ControlRegister3::new().with_i2wtm(true));
+        assert_eq!(routed.validate(), Err(ConfigError::WatermarkWithoutFifo));
+
+        let enabled = watermark.with_ctrl_reg5(ControlRegister5::new().with_fifo_enable(true));
+        assert_eq!(enabled.validate(), Ok(()));
+    }
+
+    #[test]
+    fn lint_accepts_reserved_fifo_mode() {
+        let config = Config::new()
+            .with_ctrl_reg5(ControlRegister5::new().with_fifo_enable(true))
+            .with_fifo_ctrl(FifoControlRegister::from_bits(0xE1));
+        let lints = config.lint();
+        assert!(!lints.contains(ConfigWarning::FifoEnabledInBypassMode));
+        assert!(!lints.contains(ConfigWarning::WatermarkInBypassMode));
+    }
+
+    #[test]
+    fn lint_reports_bypass_mode() {
+        let config = Config::new()
+            .with_ctrl_reg5(ControlRegister5::new().with_fifo_enable(true))
+            .with_fifo_ctrl(FifoControlRegister::new().with_watermark(1));
+        let lints = config.lint();
+        assert!(lints.contains(ConfigWarning::FifoEnabledInBypassMode));
+        assert!(lints.contains(ConfigWarning::WatermarkInBypassMode));
+    }
+}
@@ -0,0 +1,117 @@
+//! Named scaling and timing constants, e.g. for code that does not use the configuration enums.
+
+/// Sensitivity at ±250 dps full scale ([`Sensitivity::D250`](crate::Sensitivity::D250)), in mdps/digit.
+pub const MDPS_PER_DIGIT_250: f32 = 8.75;
+
+/// Sensitivity at ±500 dps full scale ([`Sensitivity::D500`](crate::Sensitivity::D500)), in mdps/digit.
+pub const MDPS_PER_DIGIT_500: f32 = 17.5;
+
+/// Sensitivity at ±2000 dps full scale ([`Sensitivity::D2000`](crate::Sensitivity::D2000)), in mdps/digit.
+pub const MDPS_PER_DIGIT_2000: f32 = 70.0;
+
+/// Zero-rate level tolerance at ±250 dps full scale, in dps.
+pub const ZERO_RATE_LEVEL_DPS_250: f32 = 10.0;
+
+/// Zero-rate level tolerance at ±500 dps full scale, in dps.
+pub const ZERO_RATE_LEVEL_DPS_500: f32 = 15.0;
+
+/// Zero-rate level tolerance at ±2000 dps full scale, in dps.
+pub const ZERO_RATE_LEVEL_DPS_2000: f32 = 75.0;
+
+/// Time for the memory content reboot triggered by
+/// [`ControlRegister5::boot`](crate::ControlRegister5::boot) to complete, in microseconds.
+///
+/// This is a conservative estimate; the datasheet does not specify the boot time.
+pub const BOOT_TIME_US: u32 = 10_000;
+
+/// Start-up time after leaving power-down mode before samples are produced, in microseconds.
+///
+/// This is a conservative estimate; see [`TURN_ON_SAMPLES_FROM_POWER_DOWN`].
+pub const TURN_ON_TIME_US: u32 = 10_000;
+
+/// Sample periods to discard after leaving power-down mode while the filters settle.
+///
+/// This is an estimate; the datasheet does not specify the turn-on behavior. Validate it
+/// against the application's accuracy requirements.
+pub const TURN_ON_SAMPLES_FROM_POWER_DOWN: u32 = 4;
+
+/// Sample periods to discard after leaving sleep mode. The sensor remains biased in sleep
+/// mode, so it turns on faster than from power-down mode.
+///
+/// This is an estimate; the datasheet does not specify the turn-on behavior.
+pub const TURN_ON_SAMPLES_FROM_SLEEP: u32 = 2;
+
+/// Update rate of the temperature output [`OUT_TEMP`](crate::RegisterAddress::OUT_TEMP), in Hertz.
+pub const TEMPERATURE_UPDATE_RATE_HZ: u32 = 1;
+
+/// Update period of the temperature output [`OUT_TEMP`](crate::RegisterAddress::OUT_TEMP),
+/// in microseconds.
+pub const TEMPERATURE_UPDATE_PERIOD_US: u32 = 1_000_000 / TEMPERATURE_UPDATE_RATE_HZ;
+
+/// Sample periods to discard after changing the full scale, output data rate or bandwidth
+/// while the sensor is running.
+///
+/// This is a conservative estimate; the datasheet does not specify the settling behavior.
+pub const SETTLING_SAMPLES_AFTER_RECONFIGURATION: u32 = 1;
+
+/// Nominal output data rates in Hertz, indexed by the `DR` bits
+/// ([`OutputDataRate::into_bits`](crate::OutputDataRate::into_bits)).
+pub const OUTPUT_DATA_RATE_HZ: [u16; 4] = [95, 190, 380, 760];
+
+/// Low-pass filter bandwidths in Hertz, indexed by the `DR` bits
+/// ([`OutputDataRate::into_bits`](crate::OutputDataRate::into_bits)) and then by the `BW` bits
+/// ([`Bandwidth::into_bits`](crate::Bandwidth::into_bits)).
+pub const BANDWIDTH_HZ: [[f32; 4]; 4] = [
+    [12.5, 25.0, 25.0, 25.0],
+    [12.5, 25.0, 50.0, 70.0],
+    [20.0, 25.0, 50.0, 100.0],
+    [30.0, 35.0, 50.0, 100.0],
+];
+
+/// Square roots of [`BANDWIDTH_HZ`], in √Hz, indexed the same way.
+#[allow(clippy::excessive_precision)]
+pub const BANDWIDTH_SQRT_HZ: [[f32; 4]; 4] = [
+    [3.5355339059327378, 5.0, 5.0, 5.0],
+    [
+        3.5355339059327378,
+        5.0,
+        7.0710678118654755,
+        8.366600265340756,
+    ],
+    [4.47213595499958, 5.0, 7.0710678118654755, 10.0],
+    [
+        5.477225575051661,
+        5.916079783099616,
+        7.0710678118654755,
+        10.0,
+    ],
+];
+
+/// High-pass filter cutoff frequencies in Hertz, indexed by the `HPCF` bits
+/// ([`ControlRegister2::hpcf`](crate::ControlRegister2::hpcf), `0` to `9`) and then by the
+/// `DR` bits ([`OutputDataRate::into_bits`](crate::OutputDataRate::into_bits)).
+pub const HIGHPASS_CUTOFF_HZ: [[f32; 4]; 10] = [
+    [7.2, 13.5, 27.0, 51.4],
+    [3.5, 7.2, 13.5, 27.0],
+    [1.8, 3.5, 7.2, 13.5],
+    [0.9, 1.8, 3.5, 7.2],
+    [0.45, 0.9, 1.8, 3.5],
+    [0.18, 0.45, 0.9, 1.8],
+    [0.09, 0.18, 0.45, 0.9],
+    [0.045, 0.09, 0.18, 0.45],
+    [0.018, 0.045, 0.09, 0.18],
+    [0.009, 0.018, 0.045, 0.09],
+];
+
+/// Sensitivities in mdps/digit, indexed by the `FS` bits
+/// ([`Sensitivity::into_bits`](crate::Sensitivity::into_bits)).
+pub const SENSITIVITY_MDPS_PER_DIGIT: [f32; 4] = [
+    MDPS_PER_DIGIT_250,
+    MDPS_PER_DIGIT_500,
+    MDPS_PER_DIGIT_2000,
+    MDPS_PER_DIGIT_2000,
+];
+
+/// Full scales in dps, indexed by the `FS` bits
+/// ([`Sensitivity::into_bits`](crate::Sensitivity::into_bits)).
+pub const FULL_SCALE_DPS: [u16; 4] = [250, 500, 2000, 2000];
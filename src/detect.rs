@@ -0,0 +1,293 @@
+//! Identification of ST gyroscopes by their `WHO_AM_I` value.
+
+use crate::{RegisterAddress, RegisterBus, DEFAULT_DEVICE_ADDRESS, DEFAULT_DEVICE_ADDRESS_ALT};
+
+/// A gyroscope of the ST family sharing the L3GD20 register layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DetectedChip {
+    /// L3GD20, `WHO_AM_I` = D4h.
+    L3GD20,
+    /// L3GD20H, `WHO_AM_I` = D7h.
+    ///
+    /// Output data rates, bandwidths and some control bits differ from the L3GD20.
+    L3GD20H,
+    /// L3G4200D or I3G4250D, `WHO_AM_I` = D3h.
+    ///
+    /// Both chips report the same identifier and cannot be told apart by reading registers.
+    L3G4200D,
+}
+
+impl DetectedChip {
+    /// The I²C addresses probed by [`detect`], in order.
+    ///
+    /// The L3GD20 and L3GD20H respond at 6Ah/6Bh, the L3G4200D and I3G4250D at 68h/69h,
+    /// depending on the level of the SDO pin.
+    pub const PROBE_ADDRESSES: [u8; 4] = [
+        DEFAULT_DEVICE_ADDRESS,
+        DEFAULT_DEVICE_ADDRESS_ALT,
+        0b0110_1000,
+        0b0110_1001,
+    ];
+
+    /// Identifies the chip from the value of [`WHO_AM_I`](RegisterAddress::WHO_AM_I).
+    pub const fn from_who_am_i(value: u8) -> Option<Self> {
+        match value {
+            0xD4 => Some(DetectedChip::L3GD20),
+            0xD7 => Some(DetectedChip::L3GD20H),
+            0xD3 => Some(DetectedChip::L3G4200D),
+            _ => None,
+        }
+    }
+
+    /// Returns the expected value of [`WHO_AM_I`](RegisterAddress::WHO_AM_I).
+    pub const fn who_am_i(self) -> u8 {
+        match self {
+            DetectedChip::L3GD20 => 0xD4,
+            DetectedChip::L3GD20H => 0xD7,
+            DetectedChip::L3G4200D => 0xD3,
+        }
+    }
+
+    /// Determines whether the register map of this crate applies without changes.
+    pub const fn is_l3gd20(self) -> bool {
+        matches!(self, DetectedChip::L3GD20)
+    }
+}
+
+/// Probes the addresses in [`DetectedChip::PROBE_ADDRESSES`] and identifies the first
+/// responding chip.
+///
+/// `read_register` is called with the 7-bit I²C device address and the register address and
+/// returns the register value, e.g. using `embedded-hal`'s `write_read`. Bus errors, such as
+/// a missing acknowledge, cause the next address to be probed.
+///
+/// Returns the device address along with the detected chip.
+pub fn detect<F, E>(mut read_register: F) -> Result<(u8, DetectedChip), DetectError<E>>
+where
+    F: FnMut(u8, u8) -> Result<u8, E>,
+{
+    let mut last_error = None;
+    let mut unknown = None;
+
+    for address in DetectedChip::PROBE_ADDRESSES {
+        match read_register(address, RegisterAddress::WHO_AM_I.addr()) {
+            Ok(value) => match DetectedChip::from_who_am_i(value) {
+                Some(chip) => return Ok((address, chip)),
+                None => {
+                    unknown.get_or_insert(DetectError::UnknownChip {
+                        address,
+                        who_am_i: value,
+                    });
+                }
+            },
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    match (unknown, last_error) {
+        (Some(unknown), _) => Err(unknown),
+        (None, Some(error)) => Err(DetectError::NoResponse(error)),
+        (None, None) => unreachable!("every probe either succeeds or fails"),
+    }
+}
+
+/// Probes the addresses in [`DetectedChip::PROBE_ADDRESSES`] using a [`RegisterBus`] per
+/// address, see [`detect`].
+///
+/// `bus_at` returns a bus bound to the 7-bit I²C device address, e.g. a handle to a shared bus.
+pub fn detect_bus<F, B>(mut bus_at: F) -> Result<(u8, DetectedChip), DetectError<B::Error>>
+where
+    F: FnMut(u8) -> B,
+    B: RegisterBus,
+{
+    detect(|address, _| bus_at(address).read_register(RegisterAddress::WHO_AM_I))
+}
+
+/// An error when detecting a chip using [`detect`] or [`detect_bus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectError<E> {
+    /// No device responded; contains the error of the last probe.
+    NoResponse(E),
+    /// A device responded, but its `WHO_AM_I` value is not known.
+    UnknownChip {
+        /// The 7-bit I²C device address.
+        address: u8,
+        /// The value read from `WHO_AM_I`.
+        who_am_i: u8,
+    },
+}
+
+impl<E> core::fmt::Display for DetectError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DetectError::NoResponse(error) => write!(f, "no gyroscope responded: {error}"),
+            DetectError::UnknownChip { address, who_am_i } => write!(
+                f,
+                "device at {address:02X}h reported unknown WHO_AM_I value {who_am_i:02X}h"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<E> std::error::Error for DetectError<E> where E: core::fmt::Debug + core::fmt::Display {}
+
+/// The result of [`sanity_check`].
+///
+/// Clones and relabeled parts of other gyroscopes are common on breakout boards. They
+/// typically report a different `WHO_AM_I` value, read back non-zero reserved bits or do not
+/// retain written values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SanityReport {
+    /// The value read from [`WHO_AM_I`](RegisterAddress::WHO_AM_I).
+    pub who_am_i: u8,
+    /// The chip identified from [`who_am_i`](Self::who_am_i), if known.
+    pub chip: Option<DetectedChip>,
+    /// The reserved bits of [`CTRL_REG4`](RegisterAddress::CTRL_REG4) read as zero.
+    pub reserved_bits_clear: bool,
+    /// Test patterns written to [`REFERENCE`](RegisterAddress::REFERENCE) were read back unchanged.
+    pub scratch_read_back: bool,
+}
+
+impl SanityReport {
+    /// The reserved bits of `CTRL_REG4`.
+    pub const CTRL_REG4_RESERVED_MASK: u8 = 0b0000_1110;
+
+    /// The test patterns written to the scratch register.
+    pub const SCRATCH_PATTERNS: [u8; 2] = [0b1010_0101, 0b0101_1010];
+
+    /// Determines whether all checks passed for an L3GD20.
+    pub const fn passed(&self) -> bool {
+        matches!(self.chip, Some(DetectedChip::L3GD20))
+            && self.reserved_bits_clear
+            && self.scratch_read_back
+    }
+}
+
+/// Checks whether the device behaves like a genuine L3GD20.
+///
+/// The check reads [`WHO_AM_I`](RegisterAddress::WHO_AM_I) and the reserved bits of
+/// [`CTRL_REG4`](RegisterAddress::CTRL_REG4), then uses [`REFERENCE`](RegisterAddress::REFERENCE)
+/// as a scratch register: test patterns are written and read back, and the original value
+/// is restored afterwards. Run the check before configuring the high-pass filter reference.
+///
+/// `read` and `write` access a single register. Bus errors abort the check.
+pub fn sanity_check<R, W, E>(mut read: R, mut write: W) -> Result<SanityReport, E>
+where
+    R: FnMut(RegisterAddress) -> Result<u8, E>,
+    W: FnMut(RegisterAddress, u8) -> Result<(), E>,
+{
+    let who_am_i = read(RegisterAddress::WHO_AM_I)?;
+    let ctrl_reg4 = read(RegisterAddress::CTRL_REG4)?;
+
+    let original = read(RegisterAddress::REFERENCE)?;
+    let mut scratch_read_back = true;
+    for pattern in SanityReport::SCRATCH_PATTERNS {
+        write(RegisterAddress::REFERENCE, pattern)?;
+        scratch_read_back &= read(RegisterAddress::REFERENCE)? == pattern;
+    }
+    write(RegisterAddress::REFERENCE, original)?;
+
+    Ok(SanityReport {
+        who_am_i,
+        chip: DetectedChip::from_who_am_i(who_am_i),
+        reserved_bits_clear: ctrl_reg4 & SanityReport::CTRL_REG4_RESERVED_MASK == 0,
+        scratch_read_back,
+    })
+}
+
+/// Checks whether the device behind a [`RegisterBus`] behaves like a genuine L3GD20,
+/// see [`sanity_check`].
+pub fn sanity_check_bus<B>(bus: &mut B) -> Result<SanityReport, B::Error>
+where
+    B: RegisterBus,
+{
+    let bus = core::cell::RefCell::new(bus);
+    sanity_check(
+        |register| bus.borrow_mut().read_register(register),
+        |register, value| bus.borrow_mut().write_register(register, value),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusTransaction, Replay, ReplayError};
+
+    const L3GD20: [BusTransaction<'static>; 1] =
+        [BusTransaction::read(RegisterAddress::WHO_AM_I, &[0xD4])];
+    const UNKNOWN: [BusTransaction<'static>; 1] =
+        [BusTransaction::read(RegisterAddress::WHO_AM_I, &[0x33])];
+
+    #[test]
+    fn detect_bus_skips_silent_addresses() {
+        let result = detect_bus(|address| match address {
+            0b0110_1000 => Replay::new(&L3GD20),
+            _ => Replay::new(&[]),
+        });
+        assert_eq!(result, Ok((0b0110_1000, DetectedChip::L3GD20)));
+    }
+
+    #[test]
+    fn detect_bus_reports_unknown_chips() {
+        let result = detect_bus(|address| match address {
+            DEFAULT_DEVICE_ADDRESS_ALT => Replay::new(&UNKNOWN),
+            _ => Replay::new(&[]),
+        });
+        assert_eq!(
+            result,
+            Err(DetectError::UnknownChip {
+                address: DEFAULT_DEVICE_ADDRESS_ALT,
+                who_am_i: 0x33
+            })
+        );
+    }
+
+    #[test]
+    fn sanity_check_bus_restores_reference() {
+        let log = [
+            BusTransaction::read(RegisterAddress::WHO_AM_I, &[0xD4]),
+            BusTransaction::read(RegisterAddress::CTRL_REG4, &[0x00]),
+            BusTransaction::read(RegisterAddress::REFERENCE, &[0x42]),
+            BusTransaction::write(RegisterAddress::REFERENCE, &[0b1010_0101]),
+            BusTransaction::read(RegisterAddress::REFERENCE, &[0b1010_0101]),
+            BusTransaction::write(RegisterAddress::REFERENCE, &[0b0101_1010]),
+            BusTransaction::read(RegisterAddress::REFERENCE, &[0b0101_1010]),
+            BusTransaction::write(RegisterAddress::REFERENCE, &[0x42]),
+        ];
+        let mut bus = Replay::new(&log);
+        let report = sanity_check_bus(&mut bus).unwrap();
+        assert!(report.passed());
+        assert!(bus.is_finished());
+    }
+
+    #[test]
+    fn sanity_check_bus_flags_reserved_bits() {
+        let log = [
+            BusTransaction::read(RegisterAddress::WHO_AM_I, &[0xD4]),
+            BusTransaction::read(RegisterAddress::CTRL_REG4, &[0x02]),
+            BusTransaction::read(RegisterAddress::REFERENCE, &[0x00]),
+            BusTransaction::write(RegisterAddress::REFERENCE, &[0b1010_0101]),
+            BusTransaction::read(RegisterAddress::REFERENCE, &[0b1010_0101]),
+            BusTransaction::write(RegisterAddress::REFERENCE, &[0b0101_1010]),
+            BusTransaction::read(RegisterAddress::REFERENCE, &[0x00]),
+            BusTransaction::write(RegisterAddress::REFERENCE, &[0x00]),
+        ];
+        let report = sanity_check_bus(&mut Replay::new(&log)).unwrap();
+        assert!(!report.reserved_bits_clear);
+        assert!(!report.scratch_read_back);
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn detect_bus_reports_missing_devices() {
+        let result = detect_bus(|_| Replay::new(&[]));
+        assert_eq!(result, Err(DetectError::NoResponse(ReplayError::Exhausted)));
+    }
+}
@@ -0,0 +1,263 @@
+//! A bus-agnostic interface to the sensor.
+
+use crate::{
+    AngularRate, AngularRateIn, ControlRegister1, ControlRegister4, OutputDataRate, RateUnit,
+    RegisterAddress, RegisterBus, Sensitivity,
+};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Common operations of an L3GD20 driver, independent of the bus.
+///
+/// Drivers for I²C and SPI, as well as simulators and test doubles, implement this trait
+/// so that application code can be written once against it.
+pub trait GyroscopeDevice {
+    /// The error type of the underlying bus or device.
+    type Error;
+
+    /// Reads the angular rate of all three axes, scaled according to the current full scale.
+    fn angular_rate(&mut self) -> Result<AngularRate, Self::Error>;
+
+    /// Reads the angular rate of all three axes in the unit `U`,
+    /// e.g. `device.angular_rate_as::<RadPerSec>()`.
+    fn angular_rate_as<U: RateUnit>(&mut self) -> Result<AngularRateIn<U>, Self::Error> {
+        self.angular_rate().map(|rate| rate.to_typed())
+    }
+
+    /// Reads the raw temperature value from [`OUT_TEMP`](crate::RegisterAddress::OUT_TEMP).
+    ///
+    /// The reading is uncalibrated and changes by -1 digit/°C.
+    fn temperature(&mut self) -> Result<i8, Self::Error>;
+
+    /// Sets the output data rate in [`CTRL_REG1`](crate::RegisterAddress::CTRL_REG1).
+    fn set_odr(&mut self, odr: OutputDataRate) -> Result<(), Self::Error>;
+
+    /// Sets the full scale in [`CTRL_REG4`](crate::RegisterAddress::CTRL_REG4).
+    fn set_scale(&mut self, scale: Sensitivity) -> Result<(), Self::Error>;
+}
+
+/// A [`GyroscopeDevice`] on top of a [`RegisterBus`].
+///
+/// The full scale is cached so that angular rates are read in a single burst. Output data is
+/// read in little endian order, see [`RegisterBus::read_angular_rate_raw`].
+#[derive(Debug, Clone)]
+pub struct BusGyroscope<B> {
+    bus: B,
+    sensitivity: Sensitivity,
+}
+
+impl<B> BusGyroscope<B> {
+    /// Wraps a bus, assuming the full scale currently configured in
+    /// [`CTRL_REG4`](RegisterAddress::CTRL_REG4).
+    pub const fn new(bus: B, sensitivity: Sensitivity) -> Self {
+        Self { bus, sensitivity }
+    }
+
+    /// Returns the cached full scale.
+    pub const fn sensitivity(&self) -> Sensitivity {
+        self.sensitivity
+    }
+
+    /// Returns the wrapped bus, e.g. for configuring registers not covered by the trait.
+    ///
+    /// Changing the full scale this way bypasses the cached value.
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+
+    /// Splits the device into a [`DataReader`], e.g. for use in an interrupt handler, and a
+    /// [`Configurator`].
+    ///
+    /// The reader uses `reader_bus`, a second handle to the same sensor, e.g. from a
+    /// shared-bus manager. The full scale is cached in `sensitivity`, so that changes made
+    /// through the configurator apply to subsequent readings.
+    pub fn split<'a, R>(
+        self,
+        reader_bus: R,
+        sensitivity: &'a SharedSensitivity,
+    ) -> (DataReader<'a, R>, Configurator<'a, B>) {
+        sensitivity.set(self.sensitivity);
+        (
+            DataReader {
+                bus: reader_bus,
+                sensitivity,
+            },
+            Configurator {
+                bus: self.bus,
+                sensitivity,
+            },
+        )
+    }
+}
+
+impl<B> BusGyroscope<B>
+where
+    B: RegisterBus,
+{
+    /// Wraps a bus, reading the full scale from [`CTRL_REG4`](RegisterAddress::CTRL_REG4).
+    pub fn from_bus(mut bus: B) -> Result<Self, B::Error> {
+        let sensitivity = bus.read::<ControlRegister4>()?.full_scale();
+        Ok(Self::new(bus, sensitivity))
+    }
+}
+
+impl<B> GyroscopeDevice for BusGyroscope<B>
+where
+    B: RegisterBus,
+{
+    type Error = B::Error;
+
+    fn angular_rate(&mut self) -> Result<AngularRate, Self::Error> {
+        let raw = self.bus.read_angular_rate_raw()?;
+        Ok(AngularRate::from_raw(raw, self.sensitivity))
+    }
+
+    fn temperature(&mut self) -> Result<i8, Self::Error> {
+        self.bus
+            .read_register(RegisterAddress::OUT_TEMP)
+            .map(|value| value as i8)
+    }
+
+    fn set_odr(&mut self, odr: OutputDataRate) -> Result<(), Self::Error> {
+        self.bus
+            .modify::<ControlRegister1, _>(|register| register.with_output_data_rate(odr))?;
+        Ok(())
+    }
+
+    fn set_scale(&mut self, scale: Sensitivity) -> Result<(), Self::Error> {
+        self.bus
+            .modify::<ControlRegister4, _>(|register| register.with_full_scale(scale))?;
+        self.sensitivity = scale;
+        Ok(())
+    }
+}
+
+/// The full scale shared by a [`DataReader`] and a [`Configurator`].
+///
+/// The value is stored atomically, so it can be shared with an interrupt handler without a
+/// critical section, e.g. in a `static`.
+#[derive(Debug)]
+pub struct SharedSensitivity(AtomicU8);
+
+impl SharedSensitivity {
+    /// Creates the cache.
+    pub const fn new(sensitivity: Sensitivity) -> Self {
+        Self(AtomicU8::new(sensitivity.into_bits()))
+    }
+
+    /// Returns the cached full scale.
+    pub fn get(&self) -> Sensitivity {
+        Sensitivity::from_bits(self.0.load(Ordering::Acquire))
+    }
+
+    /// Updates the cached full scale.
+    pub fn set(&self, sensitivity: Sensitivity) {
+        self.0.store(sensitivity.into_bits(), Ordering::Release);
+    }
+}
+
+/// The read-only half of a [`BusGyroscope`], see [`BusGyroscope::split`].
+#[derive(Debug)]
+pub struct DataReader<'a, B> {
+    bus: B,
+    sensitivity: &'a SharedSensitivity,
+}
+
+impl<'a, B> DataReader<'a, B>
+where
+    B: RegisterBus,
+{
+    /// Reads the angular rate of all three axes, scaled according to the cached full scale.
+    pub fn angular_rate(&mut self) -> Result<AngularRate, B::Error> {
+        let raw = self.bus.read_angular_rate_raw()?;
+        Ok(AngularRate::from_raw(raw, self.sensitivity.get()))
+    }
+
+    /// Reads the angular rate of all three axes in the unit `U`.
+    pub fn angular_rate_as<U: RateUnit>(&mut self) -> Result<AngularRateIn<U>, B::Error> {
+        self.angular_rate().map(|rate| rate.to_typed())
+    }
+
+    /// Reads the raw temperature value, see [`GyroscopeDevice::temperature`].
+    pub fn temperature(&mut self) -> Result<i8, B::Error> {
+        self.bus
+            .read_register(RegisterAddress::OUT_TEMP)
+            .map(|value| value as i8)
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+}
+
+/// The configuring half of a [`BusGyroscope`], see [`BusGyroscope::split`].
+#[derive(Debug)]
+pub struct Configurator<'a, B> {
+    bus: B,
+    sensitivity: &'a SharedSensitivity,
+}
+
+impl<'a, B> Configurator<'a, B>
+where
+    B: RegisterBus,
+{
+    /// Sets the output data rate in [`CTRL_REG1`](RegisterAddress::CTRL_REG1).
+    pub fn set_odr(&mut self, odr: OutputDataRate) -> Result<(), B::Error> {
+        self.bus
+            .modify::<ControlRegister1, _>(|register| register.with_output_data_rate(odr))?;
+        Ok(())
+    }
+
+    /// Sets the full scale in [`CTRL_REG4`](RegisterAddress::CTRL_REG4) and updates the
+    /// cached value used by the [`DataReader`].
+    pub fn set_scale(&mut self, scale: Sensitivity) -> Result<(), B::Error> {
+        self.bus
+            .modify::<ControlRegister4, _>(|register| register.with_full_scale(scale))?;
+        self.sensitivity.set(scale);
+        Ok(())
+    }
+
+    /// Returns the wrapped bus, e.g. for configuring registers not covered by this type.
+    ///
+    /// Changing the full scale this way bypasses the cached value.
+    pub fn bus_mut(&mut self) -> &mut B {
+        &mut self.bus
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusTransaction, Replay};
+
+    #[test]
+    fn split_shares_the_full_scale() {
+        let config_log = [
+            BusTransaction::read(RegisterAddress::CTRL_REG4, &[0x00]),
+            BusTransaction::write(RegisterAddress::CTRL_REG4, &[0x10]),
+        ];
+        let reader_log = [
+            BusTransaction::read(RegisterAddress::OUT_X_L, &[100, 0, 0, 0, 0, 0]),
+            BusTransaction::read(RegisterAddress::OUT_X_L, &[100, 0, 0, 0, 0, 0]),
+        ];
+
+        let sensitivity = SharedSensitivity::new(Sensitivity::D250);
+        let device = BusGyroscope::new(Replay::new(&config_log), Sensitivity::D250);
+        let (mut reader, mut configurator) = device.split(Replay::new(&reader_log), &sensitivity);
+
+        assert_eq!(reader.angular_rate().unwrap().x(), 100.0 * 0.00875);
+        configurator.set_scale(Sensitivity::D500).unwrap();
+        assert_eq!(sensitivity.get(), Sensitivity::D500);
+        assert_eq!(reader.angular_rate().unwrap().x(), 100.0 * 0.0175);
+    }
+}
@@ -0,0 +1,208 @@
+//! Routing of data-ready and FIFO interrupts to the DRDY/INT2 pin.
+
+use crate::{
+    Config, ConfigWarning, ControlRegister1, ControlRegister3, Lints, PowerMode, RegisterAddress,
+    StatusRegister, Transition,
+};
+
+/// The signals routed to the DRDY/INT2 pin, i.e. the `I2_*` bits of [`ControlRegister3`].
+///
+/// All signals share a single pin. Use [`lint`](Self::lint) to find combinations whose
+/// sources cannot be told apart without reading the status registers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DrdyConfig {
+    /// Data-ready on DRDY/INT2, see [`ControlRegister3::i2drdy`].
+    pub data_ready: bool,
+    /// FIFO watermark interrupt on DRDY/INT2, see [`ControlRegister3::i2wtm`].
+    pub watermark: bool,
+    /// FIFO overrun interrupt on DRDY/INT2, see [`ControlRegister3::i2orun`].
+    pub overrun: bool,
+    /// FIFO empty interrupt on DRDY/INT2, see [`ControlRegister3::i2empty`].
+    pub empty: bool,
+}
+
+impl DrdyConfig {
+    /// Creates a configuration with no signals routed to the pin.
+    pub const fn new() -> Self {
+        Self {
+            data_ready: false,
+            watermark: false,
+            overrun: false,
+            empty: false,
+        }
+    }
+
+    /// Sets whether data-ready is routed to the pin.
+    #[must_use]
+    pub const fn with_data_ready(mut self, enabled: bool) -> Self {
+        self.data_ready = enabled;
+        self
+    }
+
+    /// Sets whether the FIFO watermark interrupt is routed to the pin.
+    #[must_use]
+    pub const fn with_watermark(mut self, enabled: bool) -> Self {
+        self.watermark = enabled;
+        self
+    }
+
+    /// Sets whether the FIFO overrun interrupt is routed to the pin.
+    #[must_use]
+    pub const fn with_overrun(mut self, enabled: bool) -> Self {
+        self.overrun = enabled;
+        self
+    }
+
+    /// Sets whether the FIFO empty interrupt is routed to the pin.
+    #[must_use]
+    pub const fn with_empty(mut self, enabled: bool) -> Self {
+        self.empty = enabled;
+        self
+    }
+
+    /// Reads the DRDY/INT2 routing from a register value.
+    pub const fn from_register(value: ControlRegister3) -> Self {
+        Self {
+            data_ready: value.i2drdy(),
+            watermark: value.i2wtm(),
+            overrun: value.i2orun(),
+            empty: value.i2empty(),
+        }
+    }
+
+    /// Applies the routing to a register value, keeping the INT1 and pin configuration bits.
+    pub const fn apply(&self, value: ControlRegister3) -> ControlRegister3 {
+        value
+            .with_i2drdy(self.data_ready)
+            .with_i2wtm(self.watermark)
+            .with_i2orun(self.overrun)
+            .with_i2empty(self.empty)
+    }
+
+    /// Produces a register value with only the DRDY/INT2 routing set.
+    pub const fn to_register(&self) -> ControlRegister3 {
+        self.apply(ControlRegister3::new())
+    }
+
+    /// Determines whether any FIFO interrupt is routed to the pin.
+    pub const fn has_fifo_interrupts(&self) -> bool {
+        self.watermark || self.overrun || self.empty
+    }
+
+    /// Checks the routing for signals that share the pin and cannot be told apart.
+    pub fn lint(&self) -> Lints {
+        let mut lints = Lints::new();
+
+        if self.data_ready && self.watermark {
+            lints.insert(ConfigWarning::DataReadyAndWatermarkShared);
+        }
+
+        if self.data_ready && (self.overrun || self.empty) {
+            lints.insert(ConfigWarning::DataReadyAndFifoStatusShared);
+        }
+
+        lints
+    }
+}
+
+impl From<ControlRegister3> for DrdyConfig {
+    fn from(value: ControlRegister3) -> Self {
+        Self::from_register(value)
+    }
+}
+
+impl From<DrdyConfig> for ControlRegister3 {
+    fn from(value: DrdyConfig) -> Self {
+        value.to_register()
+    }
+}
+
+/// Masks waits for new data while the sensor does not produce any.
+///
+/// In [`PowerMode::Sleep`] and [`PowerMode::PowerDown`] the sensor stops updating the output
+/// registers, so neither the DRDY pin nor [`StatusRegister::zyx_da`] will ever signal new data.
+/// Pass every register write through [`observe_write`](Self::observe_write) to keep track of
+/// the power mode, and use [`is_armed`](Self::is_armed) or [`poll`](Self::poll) to end waits
+/// instead of blocking until the sensor is woken up again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DataReadyGate {
+    power_mode: PowerMode,
+}
+
+/// The outcome of [`DataReadyGate::poll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DataReadyPoll {
+    /// New data is available.
+    Ready(StatusRegister),
+    /// No new data is available yet.
+    Pending,
+    /// The sensor is not producing data; the status register was not read.
+    Masked,
+}
+
+impl DataReadyGate {
+    /// Creates a gate for a sensor in the specified power mode.
+    pub const fn new(power_mode: PowerMode) -> Self {
+        Self { power_mode }
+    }
+
+    /// Creates a gate for a sensor using the specified configuration.
+    pub const fn from_config(config: &Config) -> Self {
+        Self::new(config.power_mode())
+    }
+
+    /// Returns the tracked power mode.
+    pub const fn power_mode(&self) -> PowerMode {
+        self.power_mode
+    }
+
+    /// Determines whether the sensor produces data, i.e. whether waiting for it can succeed.
+    pub const fn is_armed(&self) -> bool {
+        matches!(self.power_mode, PowerMode::Normal)
+    }
+
+    /// Updates the tracked power mode from a register write.
+    ///
+    /// Writes to registers other than [`CTRL_REG1`](RegisterAddress::CTRL_REG1) are ignored.
+    /// Returns the transition to settle from when the write wakes the sensor up, if any.
+    pub fn observe_write(&mut self, addr: RegisterAddress, value: u8) -> Option<Transition> {
+        if addr != RegisterAddress::CTRL_REG1 {
+            return None;
+        }
+
+        self.set_power_mode(ControlRegister1::from_bits(value).power_mode())
+    }
+
+    /// Updates the tracked power mode, returning the transition to settle from, if any.
+    pub fn set_power_mode(&mut self, power_mode: PowerMode) -> Option<Transition> {
+        let transition = Transition::between(self.power_mode, power_mode);
+        self.power_mode = power_mode;
+        transition
+    }
+
+    /// Reads the status register unless the sensor is not producing data.
+    pub fn poll<F, E>(&self, mut read: F) -> Result<DataReadyPoll, E>
+    where
+        F: FnMut(RegisterAddress) -> Result<u8, E>,
+    {
+        if !self.is_armed() {
+            return Ok(DataReadyPoll::Masked);
+        }
+
+        let status = StatusRegister::from_bits(read(RegisterAddress::STATUS_REG)?);
+        if status.zyx_da() {
+            Ok(DataReadyPoll::Ready(status))
+        } else {
+            Ok(DataReadyPoll::Pending)
+        }
+    }
+}
+
+impl From<&Config> for DataReadyGate {
+    fn from(value: &Config) -> Self {
+        Self::from_config(value)
+    }
+}
@@ -0,0 +1,293 @@
+//! Stable numeric error codes.
+
+use crate::{
+    CalibrationError, ConfigError, DetectError, DumpError, MultiGyroError, PackError,
+    ParseEnumError, RecoverError, ReplayError, TornReadError, VerificationError, WriteVerifyError,
+};
+
+/// An error with a stable numeric code, e.g. for aggregating error statistics in telemetry.
+///
+/// Every error of this crate maps to a `u16` code whose high byte identifies the error type
+/// and whose low byte identifies the variant. Variant `0x00` denotes the wrapped bus error of
+/// generic error types. Codes are stable across releases; new variants receive new codes.
+///
+/// | Code     | Error |
+/// |----------|-------|
+/// | `0x01xx` | [`ConfigError`] |
+/// | `0x02xx` | [`CalibrationError`] |
+/// | `0x03xx` | [`PackError`] |
+/// | `0x04xx` | [`DumpError`] |
+/// | `0x05xx` | [`ParseEnumError`] |
+/// | `0x06xx` | [`VerificationError`] |
+/// | `0x07xx` | [`WriteVerifyError`] |
+/// | `0x08xx` | [`TornReadError`] |
+/// | `0x09xx` | [`DetectError`] |
+/// | `0x0Axx` | [`ReplayError`] |
+/// | `0x0Bxx` | [`MultiGyroError`] |
+/// | `0x0Cxx` | [`RecoverError`] |
+///
+/// With the `defmt` feature, errors are encoded as their code followed by the variant
+/// fields, without any strings.
+pub trait ErrorCode {
+    /// Returns the code of the error.
+    fn code(&self) -> u16;
+
+    /// Returns the error type, i.e. the high byte of the code.
+    fn category(&self) -> u8 {
+        self.code().to_be_bytes()[0]
+    }
+}
+
+impl ErrorCode for ConfigError {
+    fn code(&self) -> u16 {
+        match self {
+            ConfigError::WatermarkWithoutFifo => 0x0101,
+        }
+    }
+}
+
+impl ErrorCode for CalibrationError {
+    fn code(&self) -> u16 {
+        match self {
+            CalibrationError::InvalidLength { .. } => 0x0201,
+            CalibrationError::UnsupportedVersion(_) => 0x0202,
+        }
+    }
+}
+
+impl ErrorCode for PackError {
+    fn code(&self) -> u16 {
+        match self {
+            PackError::Empty => 0x0301,
+            PackError::BufferTooSmall { .. } => 0x0302,
+            PackError::NotWritable(_) => 0x0303,
+        }
+    }
+}
+
+impl ErrorCode for DumpError {
+    fn code(&self) -> u16 {
+        match self {
+            DumpError::InvalidStartAddress(_) => 0x0401,
+            DumpError::InvalidLength { .. } => 0x0402,
+        }
+    }
+}
+
+impl ErrorCode for ParseEnumError {
+    fn code(&self) -> u16 {
+        0x0501
+    }
+}
+
+impl ErrorCode for VerificationError {
+    fn code(&self) -> u16 {
+        0x0601
+    }
+}
+
+impl<E> ErrorCode for WriteVerifyError<E> {
+    fn code(&self) -> u16 {
+        match self {
+            WriteVerifyError::Bus(_) => 0x0700,
+            WriteVerifyError::Mismatch(_) => 0x0701,
+        }
+    }
+}
+
+impl<E> ErrorCode for TornReadError<E> {
+    fn code(&self) -> u16 {
+        match self {
+            TornReadError::Bus(_) => 0x0800,
+            TornReadError::Inconsistent => 0x0801,
+        }
+    }
+}
+
+impl<E> ErrorCode for DetectError<E> {
+    fn code(&self) -> u16 {
+        match self {
+            DetectError::NoResponse(_) => 0x0900,
+            DetectError::UnknownChip { .. } => 0x0901,
+        }
+    }
+}
+
+impl ErrorCode for ReplayError {
+    fn code(&self) -> u16 {
+        match self {
+            ReplayError::Exhausted => 0x0A01,
+            ReplayError::Mismatch { .. } => 0x0A02,
+        }
+    }
+}
+
+impl<E> ErrorCode for MultiGyroError<E> {
+    fn code(&self) -> u16 {
+        0x0B00
+    }
+}
+
+impl<E> ErrorCode for RecoverError<E> {
+    fn code(&self) -> u16 {
+        match self {
+            RecoverError::Bus(_) => 0x0C00,
+            RecoverError::UnknownChip { .. } => 0x0C01,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+mod format {
+    use super::*;
+    use defmt::{write, Format, Formatter};
+
+    impl Format for ConfigError {
+        fn format(&self, f: Formatter) {
+            write!(f, "E{=u16:04x}", self.code())
+        }
+    }
+
+    impl Format for CalibrationError {
+        fn format(&self, f: Formatter) {
+            match self {
+                CalibrationError::InvalidLength { expected, actual } => write!(
+                    f,
+                    "E{=u16:04x} {=usize} {=usize}",
+                    self.code(),
+                    expected,
+                    actual
+                ),
+                CalibrationError::UnsupportedVersion(version) => {
+                    write!(f, "E{=u16:04x} {=u8}", self.code(), version)
+                }
+            }
+        }
+    }
+
+    impl Format for PackError {
+        fn format(&self, f: Formatter) {
+            match self {
+                PackError::Empty => write!(f, "E{=u16:04x}", self.code()),
+                PackError::BufferTooSmall { required, actual } => write!(
+                    f,
+                    "E{=u16:04x} {=usize} {=usize}",
+                    self.code(),
+                    required,
+                    actual
+                ),
+                PackError::NotWritable(address) => {
+                    write!(f, "E{=u16:04x} {=u8:#04x}", self.code(), address)
+                }
+            }
+        }
+    }
+
+    impl Format for DumpError {
+        fn format(&self, f: Formatter) {
+            match self {
+                DumpError::InvalidStartAddress(address) => {
+                    write!(f, "E{=u16:04x} {=u8:#04x}", self.code(), address)
+                }
+                DumpError::InvalidLength { expected, actual } => write!(
+                    f,
+                    "E{=u16:04x} {=usize} {=usize}",
+                    self.code(),
+                    expected,
+                    actual
+                ),
+            }
+        }
+    }
+
+    impl Format for ParseEnumError {
+        fn format(&self, f: Formatter) {
+            write!(f, "E{=u16:04x}", self.code())
+        }
+    }
+
+    impl Format for VerificationError {
+        fn format(&self, f: Formatter) {
+            write!(
+                f,
+                "E{=u16:04x} {=u8:#04x} {=u8:#04x} {=u8:#04x}",
+                self.code(),
+                self.addr.addr(),
+                self.wrote,
+                self.read
+            )
+        }
+    }
+
+    impl<E: Format> Format for WriteVerifyError<E> {
+        fn format(&self, f: Formatter) {
+            match self {
+                WriteVerifyError::Bus(error) => write!(f, "E{=u16:04x} {}", self.code(), error),
+                WriteVerifyError::Mismatch(error) => error.format(f),
+            }
+        }
+    }
+
+    impl<E: Format> Format for TornReadError<E> {
+        fn format(&self, f: Formatter) {
+            match self {
+                TornReadError::Bus(error) => write!(f, "E{=u16:04x} {}", self.code(), error),
+                TornReadError::Inconsistent => write!(f, "E{=u16:04x}", self.code()),
+            }
+        }
+    }
+
+    impl<E: Format> Format for DetectError<E> {
+        fn format(&self, f: Formatter) {
+            match self {
+                DetectError::NoResponse(error) => {
+                    write!(f, "E{=u16:04x} {}", self.code(), error)
+                }
+                DetectError::UnknownChip { address, who_am_i } => write!(
+                    f,
+                    "E{=u16:04x} {=u8:#04x} {=u8:#04x}",
+                    self.code(),
+                    address,
+                    who_am_i
+                ),
+            }
+        }
+    }
+
+    impl Format for ReplayError {
+        fn format(&self, f: Formatter) {
+            match self {
+                ReplayError::Exhausted => write!(f, "E{=u16:04x}", self.code()),
+                ReplayError::Mismatch {
+                    index,
+                    direction,
+                    start,
+                } => write!(
+                    f,
+                    "E{=u16:04x} {=usize} {} {=u8:#04x}",
+                    self.code(),
+                    index,
+                    direction,
+                    start.addr()
+                ),
+            }
+        }
+    }
+
+    impl<E: Format> Format for MultiGyroError<E> {
+        fn format(&self, f: Formatter) {
+            write!(f, "E{=u16:04x} {} {}", self.code(), self.slot, self.error)
+        }
+    }
+
+    impl<E: Format> Format for RecoverError<E> {
+        fn format(&self, f: Formatter) {
+            match self {
+                RecoverError::Bus(error) => write!(f, "E{=u16:04x} {}", self.code(), error),
+                RecoverError::UnknownChip { who_am_i } => {
+                    write!(f, "E{=u16:04x} {=u8:#04x}", self.code(), who_am_i)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! C API for decoding registers and scaling raw values.
+//!
+//! All functions take and return their arguments by value, so no pointers cross the
+//! language boundary. Register values are passed as raw bytes and decoded into
+//! `#[repr(C)]` structs suitable for header generation with `cbindgen`.
+//!
+//! To link the functions from C, depend on this crate with the `ffi` feature enabled from a
+//! `staticlib` or `cdylib` crate.
+#![allow(unsafe_code)]
+
+use crate::{
+    AngularRate, AngularRateRaw, ControlRegister1, ControlRegister4, FifoSourceRegister,
+    Sensitivity, StatusRegister,
+};
+
+/// Raw angular rate reading of all three axes, in digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct L3gd20RateRaw {
+    /// X-axis angular rate.
+    pub x: i16,
+    /// Y-axis angular rate.
+    pub y: i16,
+    /// Z-axis angular rate.
+    pub z: i16,
+}
+
+/// Angular rate of all three axes, in degrees per second.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct L3gd20RateDps {
+    /// X-axis angular rate.
+    pub x: f32,
+    /// Y-axis angular rate.
+    pub y: f32,
+    /// Z-axis angular rate.
+    pub z: f32,
+}
+
+/// The output data block `OUT_X_L` (28h) through `OUT_Z_H` (2Dh) in register order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct L3gd20OutputBytes {
+    /// The raw register bytes.
+    pub bytes: [u8; 6],
+}
+
+/// Decoded `CTRL_REG1` (20h).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct L3gd20ControlRegister1 {
+    /// Output data rate in Hertz.
+    pub output_data_rate_hz: u16,
+    /// Bandwidth selection bits (`0` to `3`).
+    pub bandwidth: u8,
+    /// Normal or sleep mode if set, power-down mode otherwise.
+    pub power_up: bool,
+    /// X-axis enable.
+    pub x_enable: bool,
+    /// Y-axis enable.
+    pub y_enable: bool,
+    /// Z-axis enable.
+    pub z_enable: bool,
+}
+
+/// Decoded `CTRL_REG4` (23h).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct L3gd20ControlRegister4 {
+    /// Full scale in degrees per second (250, 500 or 2000).
+    pub full_scale_dps: u16,
+    /// Block data update.
+    pub block_data_update: bool,
+    /// MSB at the lower address if set.
+    pub big_endian: bool,
+    /// 3-wire SPI interface if set.
+    pub spi_serial_3wire: bool,
+}
+
+/// Decoded `STATUS_REG` (27h).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct L3gd20Status {
+    /// X, Y, Z-axis data overrun.
+    pub zyx_overrun: bool,
+    /// X, Y, Z-axis data available.
+    pub zyx_data_available: bool,
+}
+
+/// Decoded `FIFO_SRC_REG` (2Fh).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct L3gd20FifoSource {
+    /// FIFO filling is equal or higher than the watermark level.
+    pub watermark: bool,
+    /// FIFO is completely filled.
+    pub overrun: bool,
+    /// FIFO is empty.
+    pub empty: bool,
+    /// FIFO-stored data level.
+    pub level: u8,
+}
+
+/// Returns the sensitivity in mdps/digit for the `FS` bits of `CTRL_REG4`.
+#[no_mangle]
+pub extern "C" fn l3gd20_mdps_per_digit(full_scale_bits: u8) -> f32 {
+    Sensitivity::from_bits(full_scale_bits & 0b11).mdps_per_digit()
+}
+
+/// Decodes the output data block in little endian data order.
+#[no_mangle]
+pub extern "C" fn l3gd20_decode_output(output: L3gd20OutputBytes) -> L3gd20RateRaw {
+    let raw = AngularRateRaw::from_le_bytes(output.bytes);
+    L3gd20RateRaw {
+        x: raw.x,
+        y: raw.y,
+        z: raw.z,
+    }
+}
+
+/// Scales a raw reading to degrees per second using the `FS` bits of `CTRL_REG4`.
+#[no_mangle]
+pub extern "C" fn l3gd20_scale(raw: L3gd20RateRaw, full_scale_bits: u8) -> L3gd20RateDps {
+    let sensitivity = Sensitivity::from_bits(full_scale_bits & 0b11);
+    let [x, y, z] =
+        AngularRate::from_raw(AngularRateRaw::new(raw.x, raw.y, raw.z), sensitivity).to_dps();
+    L3gd20RateDps { x, y, z }
+}
+
+/// Decodes the value of `CTRL_REG1`.
+#[no_mangle]
+pub extern "C" fn l3gd20_decode_ctrl_reg1(value: u8) -> L3gd20ControlRegister1 {
+    let reg = ControlRegister1::from_bits(value);
+    L3gd20ControlRegister1 {
+        output_data_rate_hz: reg.output_data_rate().hz(),
+        bandwidth: reg.bandwidth().into_bits(),
+        power_up: reg.power_up(),
+        x_enable: reg.x_enable(),
+        y_enable: reg.y_enable(),
+        z_enable: reg.z_enable(),
+    }
+}
+
+/// Decodes the value of `CTRL_REG4`.
+#[no_mangle]
+pub extern "C" fn l3gd20_decode_ctrl_reg4(value: u8) -> L3gd20ControlRegister4 {
+    let reg = ControlRegister4::from_bits(value);
+    L3gd20ControlRegister4 {
+        full_scale_dps: reg.full_scale().dps(),
+        block_data_update: reg.block_data_update(),
+        big_endian: reg.big_endian(),
+        spi_serial_3wire: reg.spi_serial_3wire(),
+    }
+}
+
+/// Decodes the value of `STATUS_REG`.
+#[no_mangle]
+pub extern "C" fn l3gd20_decode_status(value: u8) -> L3gd20Status {
+    let reg = StatusRegister::from_bits(value);
+    L3gd20Status {
+        zyx_overrun: reg.zyx_overrun(),
+        zyx_data_available: reg.zyx_da(),
+    }
+}
+
+/// Decodes the value of `FIFO_SRC_REG`.
+#[no_mangle]
+pub extern "C" fn l3gd20_decode_fifo_src(value: u8) -> L3gd20FifoSource {
+    let reg = FifoSourceRegister::from_bits(value);
+    L3gd20FifoSource {
+        watermark: reg.wtm(),
+        overrun: reg.ovrn_fifo(),
+        empty: reg.empty(),
+        level: reg.fss(),
+    }
+}
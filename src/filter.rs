@@ -0,0 +1,191 @@
+//! Software filters for additional smoothing beyond the hardware bandwidth.
+
+use crate::{AngularRateRaw, Bandwidth, OutputDataRate, Rounding};
+
+/// A fixed-point single-pole IIR low-pass filter over raw readings of all three axes.
+///
+/// Computes `y += α · (x - y)` with 16 fractional bits of state, so small input changes are
+/// not lost to rounding. The first reading initializes the filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LowPassFilter {
+    /// Smoothing factor `α` with 16 fractional bits, `1 << 16` being `1.0`.
+    alpha: u32,
+    state: Option<[i64; 3]>,
+}
+
+impl LowPassFilter {
+    const ONE: u32 = 1 << 16;
+
+    /// Creates a filter with the specified -3 dB cutoff frequency at the output data rate.
+    ///
+    /// The smoothing factor is `α = 2π·fc / (2π·fc + ODR)`. Cutoff frequencies at or above the
+    /// hardware [`Bandwidth`](crate::Bandwidth) have little effect.
+    pub fn new(cutoff_hz: f32, odr: OutputDataRate) -> Self {
+        let omega = 2.0 * core::f32::consts::PI * cutoff_hz;
+        Self::with_alpha(omega / (omega + odr.hz() as f32))
+    }
+
+    /// Creates a filter with the smoothing factor `α` in `0.0..=1.0`.
+    ///
+    /// `1.0` passes readings unchanged, smaller values smooth more strongly.
+    pub fn with_alpha(alpha: f32) -> Self {
+        let alpha = (alpha.clamp(0.0, 1.0) * Self::ONE as f32) as u32;
+        Self {
+            alpha: alpha.clamp(1, Self::ONE),
+            state: None,
+        }
+    }
+
+    /// Returns the smoothing factor `α`.
+    pub fn alpha(&self) -> f32 {
+        self.alpha as f32 / Self::ONE as f32
+    }
+
+    /// Filters a reading and returns the filtered value.
+    pub fn filter(&mut self, raw: AngularRateRaw) -> AngularRateRaw {
+        let input = [raw.x, raw.y, raw.z].map(|value| (value as i64) << 16);
+        let state = match self.state {
+            None => input,
+            Some(mut state) => {
+                for (y, x) in state.iter_mut().zip(input) {
+                    *y += (self.alpha as i64 * (x - *y)) >> 16;
+                }
+                state
+            }
+        };
+        self.state = Some(state);
+        Self::output(state)
+    }
+
+    /// Returns the last filtered value, or `None` if no reading was filtered yet.
+    pub fn value(&self) -> Option<AngularRateRaw> {
+        self.state.map(Self::output)
+    }
+
+    /// Resets the filter, so that the next reading initializes it.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// Rounds the fixed-point state to digits.
+    fn output(state: [i64; 3]) -> AngularRateRaw {
+        let [x, y, z] = state.map(|value| ((value + (1 << 15)) >> 16) as i16);
+        AngularRateRaw::new(x, y, z)
+    }
+}
+
+/// Reduces the sample rate of raw readings by averaging blocks of `factor` readings.
+///
+/// Averaging only partially suppresses frequencies above the Nyquist frequency of the output
+/// rate; use [`check_aliasing`](Self::check_aliasing) to verify that the hardware
+/// [`Bandwidth`] already removes them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Decimator {
+    factor: u32,
+    sum: [i64; 3],
+    count: u32,
+}
+
+impl Decimator {
+    /// Creates a decimator that outputs one reading for every `factor` readings
+    /// (at least one).
+    pub const fn new(factor: u32) -> Self {
+        Self {
+            factor: if factor == 0 { 1 } else { factor },
+            sum: [0; 3],
+            count: 0,
+        }
+    }
+
+    /// Creates a decimator that approximates the requested output rate in Hertz
+    /// at the output data rate.
+    ///
+    /// The factor is rounded to the nearest integer; check the actual rate using
+    /// [`output_hz`](Self::output_hz).
+    pub fn for_output_rate(odr: OutputDataRate, output_hz: f32) -> Self {
+        let factor = Rounding::Nearest.round(odr.hz() as f32 / output_hz);
+        Self::new(factor.max(1) as u32)
+    }
+
+    /// Returns the decimation factor.
+    pub const fn factor(&self) -> u32 {
+        self.factor
+    }
+
+    /// Returns the output rate in Hertz at the output data rate.
+    pub fn output_hz(&self, odr: OutputDataRate) -> f32 {
+        odr.hz() as f32 / self.factor as f32
+    }
+
+    /// Checks whether the hardware bandwidth passes frequencies above the Nyquist frequency
+    /// of the output rate, which would alias into the decimated readings.
+    pub fn check_aliasing(
+        &self,
+        odr: OutputDataRate,
+        bandwidth: Bandwidth,
+    ) -> Option<AliasingWarning> {
+        let bandwidth_hz = bandwidth.hz_at(odr);
+        let nyquist_hz = 0.5 * self.output_hz(odr);
+        if bandwidth_hz <= nyquist_hz {
+            return None;
+        }
+
+        let suggested = Bandwidth::iter()
+            .rev()
+            .find(|candidate| candidate.hz_at(odr) <= nyquist_hz);
+
+        Some(AliasingWarning {
+            bandwidth_hz,
+            nyquist_hz,
+            suggested,
+        })
+    }
+
+    /// Adds a reading, returning the rounded average once `factor` readings were added.
+    pub fn push(&mut self, raw: AngularRateRaw) -> Option<AngularRateRaw> {
+        for (sum, value) in self.sum.iter_mut().zip([raw.x, raw.y, raw.z]) {
+            *sum += value as i64;
+        }
+        self.count += 1;
+        if self.count < self.factor {
+            return None;
+        }
+
+        let [x, y, z] = self
+            .sum
+            .map(|sum| Rounding::Nearest.div(sum, self.factor as i64) as i16);
+        self.reset();
+        Some(AngularRateRaw::new(x, y, z))
+    }
+
+    /// Discards the readings of the current block.
+    pub fn reset(&mut self) {
+        self.sum = [0; 3];
+        self.count = 0;
+    }
+}
+
+/// A warning that the hardware bandwidth exceeds the Nyquist frequency of a
+/// [`Decimator`] output rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AliasingWarning {
+    /// The hardware bandwidth in Hertz.
+    pub bandwidth_hz: f32,
+    /// The Nyquist frequency of the output rate in Hertz.
+    pub nyquist_hz: f32,
+    /// The widest bandwidth below the Nyquist frequency at the output data rate, if any.
+    pub suggested: Option<Bandwidth>,
+}
+
+impl core::fmt::Display for AliasingWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "bandwidth of {} Hz exceeds the Nyquist frequency of {} Hz",
+            self.bandwidth_hz, self.nyquist_hz
+        )
+    }
+}
@@ -0,0 +1,150 @@
+//! Detection of stale sensor data.
+
+use crate::{FifoSourceRegister, Measurement, OutputDataRate, StatusRegister};
+
+/// Tracks the arrival of new samples and flags when the sensor stops delivering data.
+///
+/// Given the configured [`OutputDataRate`], a new sample is expected every `1 / ODR` seconds.
+/// If no sample arrives within the tolerated number of periods, the data is considered stale,
+/// e.g. because the sensor was powered down, lost its configuration or the bus is wedged.
+///
+/// Timestamps are provided by the caller in microseconds from an arbitrary, monotonic clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FreshnessTracker {
+    period_us: u32,
+    tolerance: u32,
+    last_sample_us: Option<u64>,
+}
+
+impl FreshnessTracker {
+    /// The default number of sample periods that may pass without new data.
+    pub const DEFAULT_TOLERANCE: u32 = 3;
+
+    /// Creates a new tracker for the specified output data rate.
+    pub const fn new(odr: OutputDataRate) -> Self {
+        Self {
+            period_us: odr.period_us(),
+            tolerance: Self::DEFAULT_TOLERANCE,
+            last_sample_us: None,
+        }
+    }
+
+    /// Sets the number of sample periods that may pass without new data
+    /// before the data is considered stale. A value of zero is treated as one.
+    #[must_use]
+    pub const fn with_tolerance(mut self, periods: u32) -> Self {
+        self.tolerance = if periods == 0 { 1 } else { periods };
+        self
+    }
+
+    /// Updates the expected output data rate, e.g. after reconfiguring the sensor.
+    pub fn set_output_data_rate(&mut self, odr: OutputDataRate) {
+        self.period_us = odr.period_us();
+    }
+
+    /// Returns the time in microseconds after which data is considered stale.
+    pub const fn timeout_us(&self) -> u64 {
+        self.period_us as u64 * self.tolerance as u64
+    }
+
+    /// Records the arrival of a new sample, e.g. on a DRDY interrupt.
+    pub fn mark_fresh(&mut self, now_us: u64) {
+        self.last_sample_us = Some(now_us);
+    }
+
+    /// Records a sample if the status register indicates new data on all axes.
+    ///
+    /// Returns `true` if the sample was recorded as fresh.
+    pub fn observe(&mut self, now_us: u64, status: StatusRegister) -> bool {
+        if status.zyx_da() {
+            self.mark_fresh(now_us);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Forgets the last sample, e.g. after powering the sensor down.
+    pub fn reset(&mut self) {
+        self.last_sample_us = None;
+    }
+
+    /// Returns the time in microseconds since the last sample, if any was recorded.
+    pub fn age_us(&self, now_us: u64) -> Option<u64> {
+        self.last_sample_us.map(|last| now_us.saturating_sub(last))
+    }
+
+    /// Determines whether no new sample arrived within the expected period.
+    ///
+    /// If no sample was recorded yet, the data is considered stale.
+    pub fn is_stale(&self, now_us: u64) -> bool {
+        match self.age_us(now_us) {
+            Some(age) => age > self.timeout_us(),
+            None => true,
+        }
+    }
+}
+
+/// The quality of a sample as indicated by the overrun and data-available flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SampleQuality {
+    /// New data was available and no earlier data was lost.
+    Fresh,
+    /// No new data was available; the sample repeats an earlier reading.
+    Stale,
+    /// New data was available, but earlier data was overwritten before being read.
+    /// The sample itself is valid, but the series has a gap.
+    Overrun,
+}
+
+impl SampleQuality {
+    /// Determines the quality of a sample read along with the status register.
+    pub const fn from_status(status: StatusRegister) -> Self {
+        if status.zyx_overrun() {
+            SampleQuality::Overrun
+        } else if status.zyx_da() {
+            SampleQuality::Fresh
+        } else {
+            SampleQuality::Stale
+        }
+    }
+
+    /// Determines the quality of samples drained from the FIFO along with the FIFO source
+    /// register.
+    pub const fn from_fifo_source(source: FifoSourceRegister) -> Self {
+        if source.ovrn_fifo() {
+            SampleQuality::Overrun
+        } else if source.empty() {
+            SampleQuality::Stale
+        } else {
+            SampleQuality::Fresh
+        }
+    }
+
+    /// Determines whether the sample holds new data, i.e. is [`Fresh`](Self::Fresh)
+    /// or [`Overrun`](Self::Overrun).
+    pub const fn is_new(self) -> bool {
+        !matches!(self, SampleQuality::Stale)
+    }
+}
+
+impl From<StatusRegister> for SampleQuality {
+    fn from(value: StatusRegister) -> Self {
+        Self::from_status(value)
+    }
+}
+
+impl From<FifoSourceRegister> for SampleQuality {
+    fn from(value: FifoSourceRegister) -> Self {
+        Self::from_fifo_source(value)
+    }
+}
+
+impl Measurement {
+    /// Determines the quality of the measurement from its status flags.
+    pub const fn quality(&self) -> SampleQuality {
+        SampleQuality::from_status(self.status)
+    }
+}
@@ -1,8 +1,14 @@
 //! Gyroscope registers.
 
 use crate::types::{Bandwidth, FifoMode, HighpassFilterMode, OutputDataRate, Sensitivity};
+use crate::{FieldAccess, FieldDescriptor};
 use bitfield_struct::bitfield;
 
+/// Returns the mask of a field with the specified width and offset.
+const fn field_mask(bits: usize, offset: usize) -> u8 {
+    (u8::MAX >> (8 - bits)) << offset
+}
+
 /// The I²C bus address.
 ///
 /// The Slave ADdress (SAD) associated with the L3GD20 is `110101xb`. The SDO pin can be
@@ -10,15 +16,40 @@ use bitfield_struct::bitfield;
 /// voltage supply, LSb is `1` (address `1101011b`). Otherwise, if the SDO pin is connected to
 /// ground, the LSb value is `0` (address `1101010b`). This solution allows to connect and
 /// address two different gyroscopes to the same I²C bus.
+///
+/// This is the 7-bit address with SDO connected to ground. See [`DEFAULT_DEVICE_ADDRESS_ALT`]
+/// for the address with SDO connected to the voltage supply.
 pub const DEFAULT_DEVICE_ADDRESS: u8 = 0b0110_1010;
 
+/// The alternate I²C bus address with the SDO pin connected to the voltage supply (`1101011b`).
+///
+/// See [`DEFAULT_DEVICE_ADDRESS`] for details.
+pub const DEFAULT_DEVICE_ADDRESS_ALT: u8 = 0b0110_1011;
+
+/// Returns the 8-bit write address (SAD+W) for a 7-bit device address.
+///
+/// The 7-bit address is shifted left by one and the R/W bit is cleared, e.g.
+/// `0xD4` for [`DEFAULT_DEVICE_ADDRESS`]. Most I²C APIs, including `embedded-hal`,
+/// expect the 7-bit address instead; this is only needed for APIs taking the raw address byte.
+pub const fn i2c_write_address(device_address: u8) -> u8 {
+    device_address << 1
+}
+
+/// Returns the 8-bit read address (SAD+R) for a 7-bit device address.
+///
+/// The 7-bit address is shifted left by one and the R/W bit is set, e.g.
+/// `0xD5` for [`DEFAULT_DEVICE_ADDRESS`]. See [`i2c_write_address`].
+pub const fn i2c_read_address(device_address: u8) -> u8 {
+    (device_address << 1) | 1
+}
+
 /// Register addresses specific to the Gyroscope sensor.
 ///
 /// See also [`DEFAULT_DEVICE_ADDRESS`].
 #[allow(dead_code)]
 #[allow(non_camel_case_types)]
 #[allow(missing_docs)]
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum RegisterAddress {
     /// See [`WhoAmI`]. Read-only.
@@ -80,6 +111,245 @@ impl RegisterAddress {
     pub const fn addr(&self) -> u8 {
         *self as u8
     }
+
+    /// All registers in address order.
+    pub const ALL: [RegisterAddress; 26] = [
+        RegisterAddress::WHO_AM_I,
+        RegisterAddress::CTRL_REG1,
+        RegisterAddress::CTRL_REG2,
+        RegisterAddress::CTRL_REG3,
+        RegisterAddress::CTRL_REG4,
+        RegisterAddress::CTRL_REG5,
+        RegisterAddress::REFERENCE,
+        RegisterAddress::OUT_TEMP,
+        RegisterAddress::STATUS_REG,
+        RegisterAddress::OUT_X_L,
+        RegisterAddress::OUT_X_H,
+        RegisterAddress::OUT_Y_L,
+        RegisterAddress::OUT_Y_H,
+        RegisterAddress::OUT_Z_L,
+        RegisterAddress::OUT_Z_H,
+        RegisterAddress::FIFO_CTRL_REG,
+        RegisterAddress::FIFO_SRC_REG,
+        RegisterAddress::INT1_CFG,
+        RegisterAddress::INT1_SRC,
+        RegisterAddress::INT1_TSH_XH,
+        RegisterAddress::INT1_TSH_XL,
+        RegisterAddress::INT1_TSH_YH,
+        RegisterAddress::INT1_TSH_YL,
+        RegisterAddress::INT1_TSH_ZH,
+        RegisterAddress::INT1_TSH_ZL,
+        RegisterAddress::INT1_DURATION,
+    ];
+
+    /// Returns the register at the specified address, if any.
+    pub const fn from_addr(addr: u8) -> Option<Self> {
+        Some(match addr {
+            0x0F => RegisterAddress::WHO_AM_I,
+            0x20 => RegisterAddress::CTRL_REG1,
+            0x21 => RegisterAddress::CTRL_REG2,
+            0x22 => RegisterAddress::CTRL_REG3,
+            0x23 => RegisterAddress::CTRL_REG4,
+            0x24 => RegisterAddress::CTRL_REG5,
+            0x25 => RegisterAddress::REFERENCE,
+            0x26 => RegisterAddress::OUT_TEMP,
+            0x27 => RegisterAddress::STATUS_REG,
+            0x28 => RegisterAddress::OUT_X_L,
+            0x29 => RegisterAddress::OUT_X_H,
+            0x2A => RegisterAddress::OUT_Y_L,
+            0x2B => RegisterAddress::OUT_Y_H,
+            0x2C => RegisterAddress::OUT_Z_L,
+            0x2D => RegisterAddress::OUT_Z_H,
+            0x2E => RegisterAddress::FIFO_CTRL_REG,
+            0x2F => RegisterAddress::FIFO_SRC_REG,
+            0x30 => RegisterAddress::INT1_CFG,
+            0x31 => RegisterAddress::INT1_SRC,
+            0x32 => RegisterAddress::INT1_TSH_XH,
+            0x33 => RegisterAddress::INT1_TSH_XL,
+            0x34 => RegisterAddress::INT1_TSH_YH,
+            0x35 => RegisterAddress::INT1_TSH_YL,
+            0x36 => RegisterAddress::INT1_TSH_ZH,
+            0x37 => RegisterAddress::INT1_TSH_ZL,
+            0x38 => RegisterAddress::INT1_DURATION,
+            _ => return None,
+        })
+    }
+
+    /// Returns the datasheet name of the register, e.g. `"CTRL_REG1"`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            RegisterAddress::WHO_AM_I => "WHO_AM_I",
+            RegisterAddress::CTRL_REG1 => "CTRL_REG1",
+            RegisterAddress::CTRL_REG2 => "CTRL_REG2",
+            RegisterAddress::CTRL_REG3 => "CTRL_REG3",
+            RegisterAddress::CTRL_REG4 => "CTRL_REG4",
+            RegisterAddress::CTRL_REG5 => "CTRL_REG5",
+            RegisterAddress::REFERENCE => "REFERENCE",
+            RegisterAddress::OUT_TEMP => "OUT_TEMP",
+            RegisterAddress::STATUS_REG => "STATUS_REG",
+            RegisterAddress::OUT_X_L => "OUT_X_L",
+            RegisterAddress::OUT_X_H => "OUT_X_H",
+            RegisterAddress::OUT_Y_L => "OUT_Y_L",
+            RegisterAddress::OUT_Y_H => "OUT_Y_H",
+            RegisterAddress::OUT_Z_L => "OUT_Z_L",
+            RegisterAddress::OUT_Z_H => "OUT_Z_H",
+            RegisterAddress::FIFO_CTRL_REG => "FIFO_CTRL_REG",
+            RegisterAddress::FIFO_SRC_REG => "FIFO_SRC_REG",
+            RegisterAddress::INT1_CFG => "INT1_CFG",
+            RegisterAddress::INT1_SRC => "INT1_SRC",
+            RegisterAddress::INT1_TSH_XH => "INT1_TSH_XH",
+            RegisterAddress::INT1_TSH_XL => "INT1_TSH_XL",
+            RegisterAddress::INT1_TSH_YH => "INT1_TSH_YH",
+            RegisterAddress::INT1_TSH_YL => "INT1_TSH_YL",
+            RegisterAddress::INT1_TSH_ZH => "INT1_TSH_ZH",
+            RegisterAddress::INT1_TSH_ZL => "INT1_TSH_ZL",
+            RegisterAddress::INT1_DURATION => "INT1_DURATION",
+        }
+    }
+
+    /// Returns the register at the next higher address, i.e. the register that follows
+    /// in an auto-increment transfer, or `None` if there is a gap or no further register.
+    pub const fn next(&self) -> Option<Self> {
+        match self.addr().checked_add(1) {
+            Some(addr) => Self::from_addr(addr),
+            None => None,
+        }
+    }
+
+    /// Returns the register at the next lower address, or `None` if there is a gap
+    /// or no preceding register.
+    pub const fn prev(&self) -> Option<Self> {
+        match self.addr().checked_sub(1) {
+            Some(addr) => Self::from_addr(addr),
+            None => None,
+        }
+    }
+
+    /// Determines whether every address between this and the other register (inclusive)
+    /// is a documented register, i.e. whether both can be covered by a single
+    /// auto-increment transfer without touching reserved addresses.
+    ///
+    /// The order of both registers does not matter.
+    pub const fn contiguous_with(&self, other: &Self) -> bool {
+        let (mut addr, end) = if self.addr() <= other.addr() {
+            (self.addr(), other.addr())
+        } else {
+            (other.addr(), self.addr())
+        };
+        while addr < end {
+            addr += 1;
+            if Self::from_addr(addr).is_none() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Determines whether reading the register may change the device state.
+    ///
+    /// Reading [`INT1_SRC`](RegisterAddress::INT1_SRC) clears a latched interrupt. With the
+    /// FIFO enabled, reading the output registers [`OUT_X_L`](RegisterAddress::OUT_X_L)
+    /// through [`OUT_Z_H`](RegisterAddress::OUT_Z_H) consumes a FIFO frame.
+    pub const fn has_read_side_effects(&self) -> bool {
+        matches!(
+            self,
+            RegisterAddress::INT1_SRC
+                | RegisterAddress::OUT_X_L
+                | RegisterAddress::OUT_X_H
+                | RegisterAddress::OUT_Y_L
+                | RegisterAddress::OUT_Y_H
+                | RegisterAddress::OUT_Z_L
+                | RegisterAddress::OUT_Z_H
+        )
+    }
+
+    /// Determines whether the register is documented as writable.
+    pub const fn is_writable(&self) -> bool {
+        matches!(
+            self,
+            RegisterAddress::CTRL_REG1
+                | RegisterAddress::CTRL_REG2
+                | RegisterAddress::CTRL_REG3
+                | RegisterAddress::CTRL_REG4
+                | RegisterAddress::CTRL_REG5
+                | RegisterAddress::REFERENCE
+                | RegisterAddress::FIFO_CTRL_REG
+                | RegisterAddress::INT1_CFG
+                | RegisterAddress::INT1_TSH_XH
+                | RegisterAddress::INT1_TSH_XL
+                | RegisterAddress::INT1_TSH_YH
+                | RegisterAddress::INT1_TSH_YL
+                | RegisterAddress::INT1_TSH_ZH
+                | RegisterAddress::INT1_TSH_ZL
+                | RegisterAddress::INT1_DURATION
+        )
+    }
+
+    /// Returns the documented reset value of a register.
+    ///
+    /// Returns `None` for output and source registers, whose contents depend on the
+    /// measured data.
+    pub const fn reset_value(&self) -> Option<u8> {
+        match self {
+            RegisterAddress::WHO_AM_I => Some(0b1101_0100),
+            RegisterAddress::CTRL_REG1 => Some(0b0000_0111),
+            RegisterAddress::CTRL_REG2
+            | RegisterAddress::CTRL_REG3
+            | RegisterAddress::CTRL_REG4
+            | RegisterAddress::CTRL_REG5
+            | RegisterAddress::REFERENCE
+            | RegisterAddress::FIFO_CTRL_REG
+            | RegisterAddress::INT1_CFG
+            | RegisterAddress::INT1_TSH_XH
+            | RegisterAddress::INT1_TSH_XL
+            | RegisterAddress::INT1_TSH_YH
+            | RegisterAddress::INT1_TSH_YL
+            | RegisterAddress::INT1_TSH_ZH
+            | RegisterAddress::INT1_TSH_ZL
+            | RegisterAddress::INT1_DURATION => Some(0b0000_0000),
+            RegisterAddress::OUT_TEMP
+            | RegisterAddress::STATUS_REG
+            | RegisterAddress::OUT_X_L
+            | RegisterAddress::OUT_X_H
+            | RegisterAddress::OUT_Y_L
+            | RegisterAddress::OUT_Y_H
+            | RegisterAddress::OUT_Z_L
+            | RegisterAddress::OUT_Z_H
+            | RegisterAddress::FIFO_SRC_REG
+            | RegisterAddress::INT1_SRC => None,
+        }
+    }
+
+    /// Returns the functional category of a register.
+    pub const fn category(&self) -> Category {
+        match self {
+            RegisterAddress::WHO_AM_I => Category::Identification,
+            RegisterAddress::CTRL_REG1
+            | RegisterAddress::CTRL_REG2
+            | RegisterAddress::CTRL_REG3
+            | RegisterAddress::CTRL_REG4
+            | RegisterAddress::CTRL_REG5
+            | RegisterAddress::REFERENCE => Category::Control,
+            RegisterAddress::OUT_TEMP
+            | RegisterAddress::STATUS_REG
+            | RegisterAddress::OUT_X_L
+            | RegisterAddress::OUT_X_H
+            | RegisterAddress::OUT_Y_L
+            | RegisterAddress::OUT_Y_H
+            | RegisterAddress::OUT_Z_L
+            | RegisterAddress::OUT_Z_H => Category::Output,
+            RegisterAddress::FIFO_CTRL_REG | RegisterAddress::FIFO_SRC_REG => Category::Fifo,
+            RegisterAddress::INT1_CFG
+            | RegisterAddress::INT1_SRC
+            | RegisterAddress::INT1_TSH_XH
+            | RegisterAddress::INT1_TSH_XL
+            | RegisterAddress::INT1_TSH_YH
+            | RegisterAddress::INT1_TSH_YL
+            | RegisterAddress::INT1_TSH_ZH
+            | RegisterAddress::INT1_TSH_ZL
+            | RegisterAddress::INT1_DURATION => Category::Interrupt,
+        }
+    }
 }
 
 impl From<RegisterAddress> for u8 {
@@ -88,6 +358,22 @@ impl From<RegisterAddress> for u8 {
     }
 }
 
+/// Functional grouping of the [`RegisterAddress`] values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Category {
+    /// Device identification, i.e. [`WHO_AM_I`](RegisterAddress::WHO_AM_I).
+    Identification,
+    /// Control registers `CTRL_REG1` through `CTRL_REG5` and `REFERENCE`.
+    Control,
+    /// Temperature, status and angular rate output registers.
+    Output,
+    /// FIFO control and source registers.
+    Fifo,
+    /// INT1 configuration, source, threshold and duration registers.
+    Interrupt,
+}
+
 /// [`WHO_AM_I`](RegisterAddress::WHO_AM_I) (0Fh)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -100,6 +386,21 @@ pub struct WhoAmI {
 
 writable_register!(WhoAmI, RegisterAddress::WHO_AM_I);
 
+impl WhoAmI {
+    /// Bit position of [`ident`](Self::ident).
+    pub const IDENT_SHIFT: u8 = Self::IDENT_OFFSET as u8;
+    /// Bit mask of [`ident`](Self::ident).
+    pub const IDENT_MASK: u8 = field_mask(Self::IDENT_BITS, Self::IDENT_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "ident",
+        Self::IDENT_SHIFT,
+        Self::IDENT_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`CTRL_REG1`](RegisterAddress::CTRL_REG1) (20h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -138,6 +439,79 @@ pub struct ControlRegister1 {
 
 writable_register!(ControlRegister1, RegisterAddress::CTRL_REG1);
 
+impl ControlRegister1 {
+    /// Bit position of [`output_data_rate`](Self::output_data_rate).
+    pub const OUTPUT_DATA_RATE_SHIFT: u8 = Self::OUTPUT_DATA_RATE_OFFSET as u8;
+    /// Bit mask of [`output_data_rate`](Self::output_data_rate).
+    pub const OUTPUT_DATA_RATE_MASK: u8 =
+        field_mask(Self::OUTPUT_DATA_RATE_BITS, Self::OUTPUT_DATA_RATE_OFFSET);
+
+    /// Bit position of [`bandwidth`](Self::bandwidth).
+    pub const BANDWIDTH_SHIFT: u8 = Self::BANDWIDTH_OFFSET as u8;
+    /// Bit mask of [`bandwidth`](Self::bandwidth).
+    pub const BANDWIDTH_MASK: u8 = field_mask(Self::BANDWIDTH_BITS, Self::BANDWIDTH_OFFSET);
+
+    /// Bit position of [`power_up`](Self::power_up).
+    pub const POWER_UP_SHIFT: u8 = Self::POWER_UP_OFFSET as u8;
+    /// Bit mask of [`power_up`](Self::power_up).
+    pub const POWER_UP_MASK: u8 = field_mask(Self::POWER_UP_BITS, Self::POWER_UP_OFFSET);
+
+    /// Bit position of [`z_enable`](Self::z_enable).
+    pub const Z_ENABLE_SHIFT: u8 = Self::Z_ENABLE_OFFSET as u8;
+    /// Bit mask of [`z_enable`](Self::z_enable).
+    pub const Z_ENABLE_MASK: u8 = field_mask(Self::Z_ENABLE_BITS, Self::Z_ENABLE_OFFSET);
+
+    /// Bit position of [`x_enable`](Self::x_enable).
+    pub const X_ENABLE_SHIFT: u8 = Self::X_ENABLE_OFFSET as u8;
+    /// Bit mask of [`x_enable`](Self::x_enable).
+    pub const X_ENABLE_MASK: u8 = field_mask(Self::X_ENABLE_BITS, Self::X_ENABLE_OFFSET);
+
+    /// Bit position of [`y_enable`](Self::y_enable).
+    pub const Y_ENABLE_SHIFT: u8 = Self::Y_ENABLE_OFFSET as u8;
+    /// Bit mask of [`y_enable`](Self::y_enable).
+    pub const Y_ENABLE_MASK: u8 = field_mask(Self::Y_ENABLE_BITS, Self::Y_ENABLE_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 6] = [
+        FieldDescriptor::new(
+            "output_data_rate",
+            Self::OUTPUT_DATA_RATE_SHIFT,
+            Self::OUTPUT_DATA_RATE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "bandwidth",
+            Self::BANDWIDTH_SHIFT,
+            Self::BANDWIDTH_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "power_up",
+            Self::POWER_UP_SHIFT,
+            Self::POWER_UP_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "z_enable",
+            Self::Z_ENABLE_SHIFT,
+            Self::Z_ENABLE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "x_enable",
+            Self::X_ENABLE_SHIFT,
+            Self::X_ENABLE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "y_enable",
+            Self::Y_ENABLE_SHIFT,
+            Self::Y_ENABLE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// [`CTRL_REG2`](RegisterAddress::CTRL_REG2) (21h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -157,6 +531,34 @@ pub struct ControlRegister2 {
 
 writable_register!(ControlRegister2, RegisterAddress::CTRL_REG2);
 
+impl ControlRegister2 {
+    /// Bit position of [`hpm`](Self::hpm).
+    pub const HPM_SHIFT: u8 = Self::HPM_OFFSET as u8;
+    /// Bit mask of [`hpm`](Self::hpm).
+    pub const HPM_MASK: u8 = field_mask(Self::HPM_BITS, Self::HPM_OFFSET);
+
+    /// Bit position of [`hpcf`](Self::hpcf).
+    pub const HPCF_SHIFT: u8 = Self::HPCF_OFFSET as u8;
+    /// Bit mask of [`hpcf`](Self::hpcf).
+    pub const HPCF_MASK: u8 = field_mask(Self::HPCF_BITS, Self::HPCF_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 2] = [
+        FieldDescriptor::new(
+            "hpm",
+            Self::HPM_SHIFT,
+            Self::HPM_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "hpcf",
+            Self::HPCF_SHIFT,
+            Self::HPCF_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// [`CTRL_REG3`](RegisterAddress::CTRL_REG3) (22h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -202,6 +604,100 @@ pub struct ControlRegister3 {
 
 writable_register!(ControlRegister3, RegisterAddress::CTRL_REG3);
 
+impl ControlRegister3 {
+    /// Bit position of [`i1int1`](Self::i1int1).
+    pub const I1INT1_SHIFT: u8 = Self::I1INT1_OFFSET as u8;
+    /// Bit mask of [`i1int1`](Self::i1int1).
+    pub const I1INT1_MASK: u8 = field_mask(Self::I1INT1_BITS, Self::I1INT1_OFFSET);
+
+    /// Bit position of [`i1boot`](Self::i1boot).
+    pub const I1BOOT_SHIFT: u8 = Self::I1BOOT_OFFSET as u8;
+    /// Bit mask of [`i1boot`](Self::i1boot).
+    pub const I1BOOT_MASK: u8 = field_mask(Self::I1BOOT_BITS, Self::I1BOOT_OFFSET);
+
+    /// Bit position of [`int1_low`](Self::int1_low).
+    pub const INT1_LOW_SHIFT: u8 = Self::INT1_LOW_OFFSET as u8;
+    /// Bit mask of [`int1_low`](Self::int1_low).
+    pub const INT1_LOW_MASK: u8 = field_mask(Self::INT1_LOW_BITS, Self::INT1_LOW_OFFSET);
+
+    /// Bit position of [`open_drain`](Self::open_drain).
+    pub const OPEN_DRAIN_SHIFT: u8 = Self::OPEN_DRAIN_OFFSET as u8;
+    /// Bit mask of [`open_drain`](Self::open_drain).
+    pub const OPEN_DRAIN_MASK: u8 = field_mask(Self::OPEN_DRAIN_BITS, Self::OPEN_DRAIN_OFFSET);
+
+    /// Bit position of [`i2drdy`](Self::i2drdy).
+    pub const I2DRDY_SHIFT: u8 = Self::I2DRDY_OFFSET as u8;
+    /// Bit mask of [`i2drdy`](Self::i2drdy).
+    pub const I2DRDY_MASK: u8 = field_mask(Self::I2DRDY_BITS, Self::I2DRDY_OFFSET);
+
+    /// Bit position of [`i2wtm`](Self::i2wtm).
+    pub const I2WTM_SHIFT: u8 = Self::I2WTM_OFFSET as u8;
+    /// Bit mask of [`i2wtm`](Self::i2wtm).
+    pub const I2WTM_MASK: u8 = field_mask(Self::I2WTM_BITS, Self::I2WTM_OFFSET);
+
+    /// Bit position of [`i2orun`](Self::i2orun).
+    pub const I2ORUN_SHIFT: u8 = Self::I2ORUN_OFFSET as u8;
+    /// Bit mask of [`i2orun`](Self::i2orun).
+    pub const I2ORUN_MASK: u8 = field_mask(Self::I2ORUN_BITS, Self::I2ORUN_OFFSET);
+
+    /// Bit position of [`i2empty`](Self::i2empty).
+    pub const I2EMPTY_SHIFT: u8 = Self::I2EMPTY_OFFSET as u8;
+    /// Bit mask of [`i2empty`](Self::i2empty).
+    pub const I2EMPTY_MASK: u8 = field_mask(Self::I2EMPTY_BITS, Self::I2EMPTY_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 8] = [
+        FieldDescriptor::new(
+            "i1int1",
+            Self::I1INT1_SHIFT,
+            Self::I1INT1_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "i1boot",
+            Self::I1BOOT_SHIFT,
+            Self::I1BOOT_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "int1_low",
+            Self::INT1_LOW_SHIFT,
+            Self::INT1_LOW_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "open_drain",
+            Self::OPEN_DRAIN_SHIFT,
+            Self::OPEN_DRAIN_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "i2drdy",
+            Self::I2DRDY_SHIFT,
+            Self::I2DRDY_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "i2wtm",
+            Self::I2WTM_SHIFT,
+            Self::I2WTM_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "i2orun",
+            Self::I2ORUN_SHIFT,
+            Self::I2ORUN_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "i2empty",
+            Self::I2EMPTY_SHIFT,
+            Self::I2EMPTY_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// [`CTRL_REG4`](RegisterAddress::CTRL_REG4) (23h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -238,6 +734,58 @@ pub struct ControlRegister4 {
 
 writable_register!(ControlRegister4, RegisterAddress::CTRL_REG4);
 
+impl ControlRegister4 {
+    /// Bit position of [`block_data_update`](Self::block_data_update).
+    pub const BLOCK_DATA_UPDATE_SHIFT: u8 = Self::BLOCK_DATA_UPDATE_OFFSET as u8;
+    /// Bit mask of [`block_data_update`](Self::block_data_update).
+    pub const BLOCK_DATA_UPDATE_MASK: u8 =
+        field_mask(Self::BLOCK_DATA_UPDATE_BITS, Self::BLOCK_DATA_UPDATE_OFFSET);
+
+    /// Bit position of [`big_endian`](Self::big_endian).
+    pub const BIG_ENDIAN_SHIFT: u8 = Self::BIG_ENDIAN_OFFSET as u8;
+    /// Bit mask of [`big_endian`](Self::big_endian).
+    pub const BIG_ENDIAN_MASK: u8 = field_mask(Self::BIG_ENDIAN_BITS, Self::BIG_ENDIAN_OFFSET);
+
+    /// Bit position of [`full_scale`](Self::full_scale).
+    pub const FULL_SCALE_SHIFT: u8 = Self::FULL_SCALE_OFFSET as u8;
+    /// Bit mask of [`full_scale`](Self::full_scale).
+    pub const FULL_SCALE_MASK: u8 = field_mask(Self::FULL_SCALE_BITS, Self::FULL_SCALE_OFFSET);
+
+    /// Bit position of [`spi_serial_3wire`](Self::spi_serial_3wire).
+    pub const SPI_SERIAL_3WIRE_SHIFT: u8 = Self::SPI_SERIAL_3WIRE_OFFSET as u8;
+    /// Bit mask of [`spi_serial_3wire`](Self::spi_serial_3wire).
+    pub const SPI_SERIAL_3WIRE_MASK: u8 =
+        field_mask(Self::SPI_SERIAL_3WIRE_BITS, Self::SPI_SERIAL_3WIRE_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 4] = [
+        FieldDescriptor::new(
+            "block_data_update",
+            Self::BLOCK_DATA_UPDATE_SHIFT,
+            Self::BLOCK_DATA_UPDATE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "big_endian",
+            Self::BIG_ENDIAN_SHIFT,
+            Self::BIG_ENDIAN_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "full_scale",
+            Self::FULL_SCALE_SHIFT,
+            Self::FULL_SCALE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "spi_serial_3wire",
+            Self::SPI_SERIAL_3WIRE_SHIFT,
+            Self::SPI_SERIAL_3WIRE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// [`CTRL_REG5`](RegisterAddress::CTRL_REG5) (24h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -274,6 +822,67 @@ pub struct ControlRegister5 {
 
 writable_register!(ControlRegister5, RegisterAddress::CTRL_REG5);
 
+impl ControlRegister5 {
+    /// Bit position of [`boot`](Self::boot).
+    pub const BOOT_SHIFT: u8 = Self::BOOT_OFFSET as u8;
+    /// Bit mask of [`boot`](Self::boot).
+    pub const BOOT_MASK: u8 = field_mask(Self::BOOT_BITS, Self::BOOT_OFFSET);
+
+    /// Bit position of [`fifo_enable`](Self::fifo_enable).
+    pub const FIFO_ENABLE_SHIFT: u8 = Self::FIFO_ENABLE_OFFSET as u8;
+    /// Bit mask of [`fifo_enable`](Self::fifo_enable).
+    pub const FIFO_ENABLE_MASK: u8 = field_mask(Self::FIFO_ENABLE_BITS, Self::FIFO_ENABLE_OFFSET);
+
+    /// Bit position of [`hpen`](Self::hpen).
+    pub const HPEN_SHIFT: u8 = Self::HPEN_OFFSET as u8;
+    /// Bit mask of [`hpen`](Self::hpen).
+    pub const HPEN_MASK: u8 = field_mask(Self::HPEN_BITS, Self::HPEN_OFFSET);
+
+    /// Bit position of [`int1_sel`](Self::int1_sel).
+    pub const INT1_SEL_SHIFT: u8 = Self::INT1_SEL_OFFSET as u8;
+    /// Bit mask of [`int1_sel`](Self::int1_sel).
+    pub const INT1_SEL_MASK: u8 = field_mask(Self::INT1_SEL_BITS, Self::INT1_SEL_OFFSET);
+
+    /// Bit position of [`out_sel`](Self::out_sel).
+    pub const OUT_SEL_SHIFT: u8 = Self::OUT_SEL_OFFSET as u8;
+    /// Bit mask of [`out_sel`](Self::out_sel).
+    pub const OUT_SEL_MASK: u8 = field_mask(Self::OUT_SEL_BITS, Self::OUT_SEL_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 5] = [
+        FieldDescriptor::new(
+            "boot",
+            Self::BOOT_SHIFT,
+            Self::BOOT_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "fifo_enable",
+            Self::FIFO_ENABLE_SHIFT,
+            Self::FIFO_ENABLE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "hpen",
+            Self::HPEN_SHIFT,
+            Self::HPEN_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "int1_sel",
+            Self::INT1_SEL_SHIFT,
+            Self::INT1_SEL_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "out_sel",
+            Self::OUT_SEL_SHIFT,
+            Self::OUT_SEL_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// ## Reference / Data capture register.
 ///
 /// Reference value for interrupt generation.
@@ -290,6 +899,21 @@ pub struct ReferenceRegister {
 
 writable_register!(ReferenceRegister, RegisterAddress::REFERENCE);
 
+impl ReferenceRegister {
+    /// Bit position of [`reference`](Self::reference).
+    pub const REFERENCE_SHIFT: u8 = Self::REFERENCE_OFFSET as u8;
+    /// Bit mask of [`reference`](Self::reference).
+    pub const REFERENCE_MASK: u8 = field_mask(Self::REFERENCE_BITS, Self::REFERENCE_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "reference",
+        Self::REFERENCE_SHIFT,
+        Self::REFERENCE_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`OUT_TEMP`](RegisterAddress::OUT_TEMP) (26h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -303,6 +927,21 @@ pub struct TemperatureRegister {
 
 readable_register!(TemperatureRegister, RegisterAddress::OUT_TEMP);
 
+impl TemperatureRegister {
+    /// Bit position of [`temp`](Self::temp).
+    pub const TEMP_SHIFT: u8 = Self::TEMP_OFFSET as u8;
+    /// Bit mask of [`temp`](Self::temp).
+    pub const TEMP_MASK: u8 = field_mask(Self::TEMP_BITS, Self::TEMP_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "temp",
+        Self::TEMP_SHIFT,
+        Self::TEMP_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`STATUS_REG`](RegisterAddress::STATUS_REG) (27h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -343,6 +982,100 @@ pub struct StatusRegister {
 
 readable_register!(StatusRegister, RegisterAddress::STATUS_REG);
 
+impl StatusRegister {
+    /// Bit position of [`zyx_overrun`](Self::zyx_overrun).
+    pub const ZYX_OVERRUN_SHIFT: u8 = Self::ZYX_OVERRUN_OFFSET as u8;
+    /// Bit mask of [`zyx_overrun`](Self::zyx_overrun).
+    pub const ZYX_OVERRUN_MASK: u8 = field_mask(Self::ZYX_OVERRUN_BITS, Self::ZYX_OVERRUN_OFFSET);
+
+    /// Bit position of [`z_overrun`](Self::z_overrun).
+    pub const Z_OVERRUN_SHIFT: u8 = Self::Z_OVERRUN_OFFSET as u8;
+    /// Bit mask of [`z_overrun`](Self::z_overrun).
+    pub const Z_OVERRUN_MASK: u8 = field_mask(Self::Z_OVERRUN_BITS, Self::Z_OVERRUN_OFFSET);
+
+    /// Bit position of [`y_overrun`](Self::y_overrun).
+    pub const Y_OVERRUN_SHIFT: u8 = Self::Y_OVERRUN_OFFSET as u8;
+    /// Bit mask of [`y_overrun`](Self::y_overrun).
+    pub const Y_OVERRUN_MASK: u8 = field_mask(Self::Y_OVERRUN_BITS, Self::Y_OVERRUN_OFFSET);
+
+    /// Bit position of [`x_overrun`](Self::x_overrun).
+    pub const X_OVERRUN_SHIFT: u8 = Self::X_OVERRUN_OFFSET as u8;
+    /// Bit mask of [`x_overrun`](Self::x_overrun).
+    pub const X_OVERRUN_MASK: u8 = field_mask(Self::X_OVERRUN_BITS, Self::X_OVERRUN_OFFSET);
+
+    /// Bit position of [`zyx_da`](Self::zyx_da).
+    pub const ZYX_DA_SHIFT: u8 = Self::ZYX_DA_OFFSET as u8;
+    /// Bit mask of [`zyx_da`](Self::zyx_da).
+    pub const ZYX_DA_MASK: u8 = field_mask(Self::ZYX_DA_BITS, Self::ZYX_DA_OFFSET);
+
+    /// Bit position of [`z_da`](Self::z_da).
+    pub const Z_DA_SHIFT: u8 = Self::Z_DA_OFFSET as u8;
+    /// Bit mask of [`z_da`](Self::z_da).
+    pub const Z_DA_MASK: u8 = field_mask(Self::Z_DA_BITS, Self::Z_DA_OFFSET);
+
+    /// Bit position of [`y_da`](Self::y_da).
+    pub const Y_DA_SHIFT: u8 = Self::Y_DA_OFFSET as u8;
+    /// Bit mask of [`y_da`](Self::y_da).
+    pub const Y_DA_MASK: u8 = field_mask(Self::Y_DA_BITS, Self::Y_DA_OFFSET);
+
+    /// Bit position of [`x_da`](Self::x_da).
+    pub const X_DA_SHIFT: u8 = Self::X_DA_OFFSET as u8;
+    /// Bit mask of [`x_da`](Self::x_da).
+    pub const X_DA_MASK: u8 = field_mask(Self::X_DA_BITS, Self::X_DA_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 8] = [
+        FieldDescriptor::new(
+            "zyx_overrun",
+            Self::ZYX_OVERRUN_SHIFT,
+            Self::ZYX_OVERRUN_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "z_overrun",
+            Self::Z_OVERRUN_SHIFT,
+            Self::Z_OVERRUN_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "y_overrun",
+            Self::Y_OVERRUN_SHIFT,
+            Self::Y_OVERRUN_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "x_overrun",
+            Self::X_OVERRUN_SHIFT,
+            Self::X_OVERRUN_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "zyx_da",
+            Self::ZYX_DA_SHIFT,
+            Self::ZYX_DA_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "z_da",
+            Self::Z_DA_SHIFT,
+            Self::Z_DA_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "y_da",
+            Self::Y_DA_SHIFT,
+            Self::Y_DA_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "x_da",
+            Self::X_DA_SHIFT,
+            Self::X_DA_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+    ];
+}
+
 /// [`OUT_X_L`](RegisterAddress::OUT_X_L) (28h)
 ///
 /// Low byte of the 16-bit angular rate value. See [`OutXHigh`] for the high byte.
@@ -368,6 +1101,21 @@ pub struct OutXLow {
 
 readable_register!(OutXLow, RegisterAddress::OUT_X_L);
 
+impl OutXLow {
+    /// Bit position of [`bits`](Self::bits).
+    pub const BITS_SHIFT: u8 = Self::BITS_OFFSET as u8;
+    /// Bit mask of [`bits`](Self::bits).
+    pub const BITS_MASK: u8 = field_mask(Self::BITS_BITS, Self::BITS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "bits",
+        Self::BITS_SHIFT,
+        Self::BITS_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`OUT_X_H`](RegisterAddress::OUT_X_H) (29h)
 ///
 /// High byte of the 16-bit angular rate value. See [`OutXLow`] for the low byte.
@@ -393,6 +1141,21 @@ pub struct OutXHigh {
 
 readable_register!(OutXHigh, RegisterAddress::OUT_X_H);
 
+impl OutXHigh {
+    /// Bit position of [`bits`](Self::bits).
+    pub const BITS_SHIFT: u8 = Self::BITS_OFFSET as u8;
+    /// Bit mask of [`bits`](Self::bits).
+    pub const BITS_MASK: u8 = field_mask(Self::BITS_BITS, Self::BITS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "bits",
+        Self::BITS_SHIFT,
+        Self::BITS_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`OUT_Y_L`](RegisterAddress::OUT_Y_L) (2Ah)
 ///
 /// Low byte of the 16-bit angular rate value. See [`OutYHigh`] for the high byte.
@@ -418,6 +1181,21 @@ pub struct OutYLow {
 
 readable_register!(OutYLow, RegisterAddress::OUT_Y_L);
 
+impl OutYLow {
+    /// Bit position of [`bits`](Self::bits).
+    pub const BITS_SHIFT: u8 = Self::BITS_OFFSET as u8;
+    /// Bit mask of [`bits`](Self::bits).
+    pub const BITS_MASK: u8 = field_mask(Self::BITS_BITS, Self::BITS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "bits",
+        Self::BITS_SHIFT,
+        Self::BITS_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`OUT_Y_H`](RegisterAddress::OUT_Y_H) (2Bh)
 ///
 /// High byte of the 16-bit angular rate value. See [`OutYLow`] for the low byte.
@@ -443,6 +1221,21 @@ pub struct OutYHigh {
 
 readable_register!(OutYHigh, RegisterAddress::OUT_Y_H);
 
+impl OutYHigh {
+    /// Bit position of [`bits`](Self::bits).
+    pub const BITS_SHIFT: u8 = Self::BITS_OFFSET as u8;
+    /// Bit mask of [`bits`](Self::bits).
+    pub const BITS_MASK: u8 = field_mask(Self::BITS_BITS, Self::BITS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "bits",
+        Self::BITS_SHIFT,
+        Self::BITS_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`OUT_Z_L`](RegisterAddress::OUT_Z_L) (2Ch)
 ///
 /// Low byte of the 16-bit angular rate value. See [`OutZHigh`] for the high byte.
@@ -468,6 +1261,21 @@ pub struct OutZLow {
 
 readable_register!(OutZLow, RegisterAddress::OUT_Z_L);
 
+impl OutZLow {
+    /// Bit position of [`bits`](Self::bits).
+    pub const BITS_SHIFT: u8 = Self::BITS_OFFSET as u8;
+    /// Bit mask of [`bits`](Self::bits).
+    pub const BITS_MASK: u8 = field_mask(Self::BITS_BITS, Self::BITS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "bits",
+        Self::BITS_SHIFT,
+        Self::BITS_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`OUT_Z_H`](RegisterAddress::OUT_Z_H) (2Dh)
 ///
 /// High byte of the 16-bit angular rate value. See [`OutZLow`] for the low byte.
@@ -493,12 +1301,30 @@ pub struct OutZHigh {
 
 readable_register!(OutZHigh, RegisterAddress::OUT_Z_H);
 
+impl OutZHigh {
+    /// Bit position of [`bits`](Self::bits).
+    pub const BITS_SHIFT: u8 = Self::BITS_OFFSET as u8;
+    /// Bit mask of [`bits`](Self::bits).
+    pub const BITS_MASK: u8 = field_mask(Self::BITS_BITS, Self::BITS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "bits",
+        Self::BITS_SHIFT,
+        Self::BITS_BITS as u8,
+        FieldAccess::ReadOnly,
+    )];
+}
+
 /// [`FIFO_CTRL_REG`](RegisterAddress::FIFO_CTRL_REG) (2Eh)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct FifoControlRegister {
     /// FIFO mode selection
+    ///
+    /// The reserved values `0b101` to `0b111` read as [`FifoMode::Bypass`]; use
+    /// [`try_fifo_mode`](Self::try_fifo_mode) to detect them.
     #[bits(3, access = RW, default = FifoMode::Bypass)]
     pub fifo_mode: FifoMode,
 
@@ -509,6 +1335,39 @@ pub struct FifoControlRegister {
 
 writable_register!(FifoControlRegister, RegisterAddress::FIFO_CTRL_REG);
 
+impl FifoControlRegister {
+    /// Bit position of [`fifo_mode`](Self::fifo_mode).
+    pub const FIFO_MODE_SHIFT: u8 = Self::FIFO_MODE_OFFSET as u8;
+    /// Bit mask of [`fifo_mode`](Self::fifo_mode).
+    pub const FIFO_MODE_MASK: u8 = field_mask(Self::FIFO_MODE_BITS, Self::FIFO_MODE_OFFSET);
+
+    /// Bit position of [`watermark`](Self::watermark).
+    pub const WATERMARK_SHIFT: u8 = Self::WATERMARK_OFFSET as u8;
+    /// Bit mask of [`watermark`](Self::watermark).
+    pub const WATERMARK_MASK: u8 = field_mask(Self::WATERMARK_BITS, Self::WATERMARK_OFFSET);
+
+    /// Returns the FIFO mode, or `None` if the field holds a reserved value.
+    pub const fn try_fifo_mode(&self) -> Option<FifoMode> {
+        FifoMode::try_from_bits((self.into_bits() & Self::FIFO_MODE_MASK) >> Self::FIFO_MODE_SHIFT)
+    }
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 2] = [
+        FieldDescriptor::new(
+            "fifo_mode",
+            Self::FIFO_MODE_SHIFT,
+            Self::FIFO_MODE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "watermark",
+            Self::WATERMARK_SHIFT,
+            Self::WATERMARK_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// [`FIFO_CTRL_REG`](RegisterAddress::FIFO_SRC_REG) (2Fh)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -539,16 +1398,66 @@ pub struct FifoSourceRegister {
 
 readable_register!(FifoSourceRegister, RegisterAddress::FIFO_SRC_REG);
 
+impl FifoSourceRegister {
+    /// Bit position of [`wtm`](Self::wtm).
+    pub const WTM_SHIFT: u8 = Self::WTM_OFFSET as u8;
+    /// Bit mask of [`wtm`](Self::wtm).
+    pub const WTM_MASK: u8 = field_mask(Self::WTM_BITS, Self::WTM_OFFSET);
+
+    /// Bit position of [`ovrn_fifo`](Self::ovrn_fifo).
+    pub const OVRN_FIFO_SHIFT: u8 = Self::OVRN_FIFO_OFFSET as u8;
+    /// Bit mask of [`ovrn_fifo`](Self::ovrn_fifo).
+    pub const OVRN_FIFO_MASK: u8 = field_mask(Self::OVRN_FIFO_BITS, Self::OVRN_FIFO_OFFSET);
+
+    /// Bit position of [`empty`](Self::empty).
+    pub const EMPTY_SHIFT: u8 = Self::EMPTY_OFFSET as u8;
+    /// Bit mask of [`empty`](Self::empty).
+    pub const EMPTY_MASK: u8 = field_mask(Self::EMPTY_BITS, Self::EMPTY_OFFSET);
+
+    /// Bit position of [`fss`](Self::fss).
+    pub const FSS_SHIFT: u8 = Self::FSS_OFFSET as u8;
+    /// Bit mask of [`fss`](Self::fss).
+    pub const FSS_MASK: u8 = field_mask(Self::FSS_BITS, Self::FSS_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 4] = [
+        FieldDescriptor::new(
+            "wtm",
+            Self::WTM_SHIFT,
+            Self::WTM_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "ovrn_fifo",
+            Self::OVRN_FIFO_SHIFT,
+            Self::OVRN_FIFO_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "empty",
+            Self::EMPTY_SHIFT,
+            Self::EMPTY_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "fss",
+            Self::FSS_SHIFT,
+            Self::FSS_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+    ];
+}
+
 /// [`INT1_CFG`](RegisterAddress::INT1_CFG) (30h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Int1ConfigurationRegister {
-    /// AND/OR combination of interrupt events.
+    /// AND/OR combination of interrupt events. See [`TriggerMode`](crate::TriggerMode).
     #[bits(1, access = RW)]
     pub aoi: bool,
 
-    /// Latch interrupt request.
+    /// Latch interrupt request. See [`TriggerMode`](crate::TriggerMode).
     #[bits(1, access = RW)]
     pub lir: bool,
 
@@ -579,6 +1488,100 @@ pub struct Int1ConfigurationRegister {
 
 writable_register!(Int1ConfigurationRegister, RegisterAddress::INT1_CFG);
 
+impl Int1ConfigurationRegister {
+    /// Bit position of [`aoi`](Self::aoi).
+    pub const AOI_SHIFT: u8 = Self::AOI_OFFSET as u8;
+    /// Bit mask of [`aoi`](Self::aoi).
+    pub const AOI_MASK: u8 = field_mask(Self::AOI_BITS, Self::AOI_OFFSET);
+
+    /// Bit position of [`lir`](Self::lir).
+    pub const LIR_SHIFT: u8 = Self::LIR_OFFSET as u8;
+    /// Bit mask of [`lir`](Self::lir).
+    pub const LIR_MASK: u8 = field_mask(Self::LIR_BITS, Self::LIR_OFFSET);
+
+    /// Bit position of [`zhie`](Self::zhie).
+    pub const ZHIE_SHIFT: u8 = Self::ZHIE_OFFSET as u8;
+    /// Bit mask of [`zhie`](Self::zhie).
+    pub const ZHIE_MASK: u8 = field_mask(Self::ZHIE_BITS, Self::ZHIE_OFFSET);
+
+    /// Bit position of [`zlie`](Self::zlie).
+    pub const ZLIE_SHIFT: u8 = Self::ZLIE_OFFSET as u8;
+    /// Bit mask of [`zlie`](Self::zlie).
+    pub const ZLIE_MASK: u8 = field_mask(Self::ZLIE_BITS, Self::ZLIE_OFFSET);
+
+    /// Bit position of [`yhie`](Self::yhie).
+    pub const YHIE_SHIFT: u8 = Self::YHIE_OFFSET as u8;
+    /// Bit mask of [`yhie`](Self::yhie).
+    pub const YHIE_MASK: u8 = field_mask(Self::YHIE_BITS, Self::YHIE_OFFSET);
+
+    /// Bit position of [`ylie`](Self::ylie).
+    pub const YLIE_SHIFT: u8 = Self::YLIE_OFFSET as u8;
+    /// Bit mask of [`ylie`](Self::ylie).
+    pub const YLIE_MASK: u8 = field_mask(Self::YLIE_BITS, Self::YLIE_OFFSET);
+
+    /// Bit position of [`xhie`](Self::xhie).
+    pub const XHIE_SHIFT: u8 = Self::XHIE_OFFSET as u8;
+    /// Bit mask of [`xhie`](Self::xhie).
+    pub const XHIE_MASK: u8 = field_mask(Self::XHIE_BITS, Self::XHIE_OFFSET);
+
+    /// Bit position of [`xlie`](Self::xlie).
+    pub const XLIE_SHIFT: u8 = Self::XLIE_OFFSET as u8;
+    /// Bit mask of [`xlie`](Self::xlie).
+    pub const XLIE_MASK: u8 = field_mask(Self::XLIE_BITS, Self::XLIE_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 8] = [
+        FieldDescriptor::new(
+            "aoi",
+            Self::AOI_SHIFT,
+            Self::AOI_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "lir",
+            Self::LIR_SHIFT,
+            Self::LIR_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "zhie",
+            Self::ZHIE_SHIFT,
+            Self::ZHIE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "zlie",
+            Self::ZLIE_SHIFT,
+            Self::ZLIE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "yhie",
+            Self::YHIE_SHIFT,
+            Self::YHIE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "ylie",
+            Self::YLIE_SHIFT,
+            Self::YLIE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "xhie",
+            Self::XHIE_SHIFT,
+            Self::XHIE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "xlie",
+            Self::XLIE_SHIFT,
+            Self::XLIE_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
+
 /// [`INT1_SRC`](RegisterAddress::INT1_SRC) (31h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -618,6 +1621,89 @@ pub struct Int1SourceRegisterA {
 
 readable_register!(Int1SourceRegisterA, RegisterAddress::INT1_SRC);
 
+impl Int1SourceRegisterA {
+    /// Bit position of [`ia`](Self::ia).
+    pub const IA_SHIFT: u8 = Self::IA_OFFSET as u8;
+    /// Bit mask of [`ia`](Self::ia).
+    pub const IA_MASK: u8 = field_mask(Self::IA_BITS, Self::IA_OFFSET);
+
+    /// Bit position of [`z_high`](Self::z_high).
+    pub const Z_HIGH_SHIFT: u8 = Self::Z_HIGH_OFFSET as u8;
+    /// Bit mask of [`z_high`](Self::z_high).
+    pub const Z_HIGH_MASK: u8 = field_mask(Self::Z_HIGH_BITS, Self::Z_HIGH_OFFSET);
+
+    /// Bit position of [`z_low`](Self::z_low).
+    pub const Z_LOW_SHIFT: u8 = Self::Z_LOW_OFFSET as u8;
+    /// Bit mask of [`z_low`](Self::z_low).
+    pub const Z_LOW_MASK: u8 = field_mask(Self::Z_LOW_BITS, Self::Z_LOW_OFFSET);
+
+    /// Bit position of [`y_high`](Self::y_high).
+    pub const Y_HIGH_SHIFT: u8 = Self::Y_HIGH_OFFSET as u8;
+    /// Bit mask of [`y_high`](Self::y_high).
+    pub const Y_HIGH_MASK: u8 = field_mask(Self::Y_HIGH_BITS, Self::Y_HIGH_OFFSET);
+
+    /// Bit position of [`y_low`](Self::y_low).
+    pub const Y_LOW_SHIFT: u8 = Self::Y_LOW_OFFSET as u8;
+    /// Bit mask of [`y_low`](Self::y_low).
+    pub const Y_LOW_MASK: u8 = field_mask(Self::Y_LOW_BITS, Self::Y_LOW_OFFSET);
+
+    /// Bit position of [`x_high`](Self::x_high).
+    pub const X_HIGH_SHIFT: u8 = Self::X_HIGH_OFFSET as u8;
+    /// Bit mask of [`x_high`](Self::x_high).
+    pub const X_HIGH_MASK: u8 = field_mask(Self::X_HIGH_BITS, Self::X_HIGH_OFFSET);
+
+    /// Bit position of [`x_low`](Self::x_low).
+    pub const X_LOW_SHIFT: u8 = Self::X_LOW_OFFSET as u8;
+    /// Bit mask of [`x_low`](Self::x_low).
+    pub const X_LOW_MASK: u8 = field_mask(Self::X_LOW_BITS, Self::X_LOW_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 7] = [
+        FieldDescriptor::new(
+            "ia",
+            Self::IA_SHIFT,
+            Self::IA_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "z_high",
+            Self::Z_HIGH_SHIFT,
+            Self::Z_HIGH_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "z_low",
+            Self::Z_LOW_SHIFT,
+            Self::Z_LOW_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "y_high",
+            Self::Y_HIGH_SHIFT,
+            Self::Y_HIGH_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "y_low",
+            Self::Y_LOW_SHIFT,
+            Self::Y_LOW_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "x_high",
+            Self::X_HIGH_SHIFT,
+            Self::X_HIGH_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+        FieldDescriptor::new(
+            "x_low",
+            Self::X_LOW_SHIFT,
+            Self::X_LOW_BITS as u8,
+            FieldAccess::ReadOnly,
+        ),
+    ];
+}
+
 /// [`INT1_TSH_XH`](RegisterAddress::INT1_TSH_XH) (32h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -633,6 +1719,21 @@ pub struct Int1ThresholdRegisterXH {
 
 writable_register!(Int1ThresholdRegisterXH, RegisterAddress::INT1_TSH_XH);
 
+impl Int1ThresholdRegisterXH {
+    /// Bit position of [`threshold`](Self::threshold).
+    pub const THRESHOLD_SHIFT: u8 = Self::THRESHOLD_OFFSET as u8;
+    /// Bit mask of [`threshold`](Self::threshold).
+    pub const THRESHOLD_MASK: u8 = field_mask(Self::THRESHOLD_BITS, Self::THRESHOLD_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "threshold",
+        Self::THRESHOLD_SHIFT,
+        Self::THRESHOLD_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`INT1_TSH_XL`](RegisterAddress::INT1_TSH_XL) (33h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -645,6 +1746,21 @@ pub struct Int1ThresholdRegisterXL {
 
 writable_register!(Int1ThresholdRegisterXL, RegisterAddress::INT1_TSH_XL);
 
+impl Int1ThresholdRegisterXL {
+    /// Bit position of [`threshold`](Self::threshold).
+    pub const THRESHOLD_SHIFT: u8 = Self::THRESHOLD_OFFSET as u8;
+    /// Bit mask of [`threshold`](Self::threshold).
+    pub const THRESHOLD_MASK: u8 = field_mask(Self::THRESHOLD_BITS, Self::THRESHOLD_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "threshold",
+        Self::THRESHOLD_SHIFT,
+        Self::THRESHOLD_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`INT1_TSH_YH`](RegisterAddress::INT1_TSH_YH) (34h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -660,6 +1776,21 @@ pub struct Int1ThresholdRegisterYH {
 
 writable_register!(Int1ThresholdRegisterYH, RegisterAddress::INT1_TSH_YH);
 
+impl Int1ThresholdRegisterYH {
+    /// Bit position of [`threshold`](Self::threshold).
+    pub const THRESHOLD_SHIFT: u8 = Self::THRESHOLD_OFFSET as u8;
+    /// Bit mask of [`threshold`](Self::threshold).
+    pub const THRESHOLD_MASK: u8 = field_mask(Self::THRESHOLD_BITS, Self::THRESHOLD_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "threshold",
+        Self::THRESHOLD_SHIFT,
+        Self::THRESHOLD_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`INT1_TSH_YL`](RegisterAddress::INT1_TSH_YL) (35h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -672,6 +1803,21 @@ pub struct Int1ThresholdRegisterYL {
 
 writable_register!(Int1ThresholdRegisterYL, RegisterAddress::INT1_TSH_YL);
 
+impl Int1ThresholdRegisterYL {
+    /// Bit position of [`threshold`](Self::threshold).
+    pub const THRESHOLD_SHIFT: u8 = Self::THRESHOLD_OFFSET as u8;
+    /// Bit mask of [`threshold`](Self::threshold).
+    pub const THRESHOLD_MASK: u8 = field_mask(Self::THRESHOLD_BITS, Self::THRESHOLD_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "threshold",
+        Self::THRESHOLD_SHIFT,
+        Self::THRESHOLD_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`INT1_TSH_ZH`](RegisterAddress::INT1_TSH_ZH) (36h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -687,6 +1833,21 @@ pub struct Int1ThresholdRegisterZH {
 
 writable_register!(Int1ThresholdRegisterZH, RegisterAddress::INT1_TSH_ZH);
 
+impl Int1ThresholdRegisterZH {
+    /// Bit position of [`threshold`](Self::threshold).
+    pub const THRESHOLD_SHIFT: u8 = Self::THRESHOLD_OFFSET as u8;
+    /// Bit mask of [`threshold`](Self::threshold).
+    pub const THRESHOLD_MASK: u8 = field_mask(Self::THRESHOLD_BITS, Self::THRESHOLD_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "threshold",
+        Self::THRESHOLD_SHIFT,
+        Self::THRESHOLD_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`INT1_TSH_ZL`](RegisterAddress::INT1_TSH_ZL) (37h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -699,6 +1860,21 @@ pub struct Int1ThresholdRegisterZL {
 
 writable_register!(Int1ThresholdRegisterZL, RegisterAddress::INT1_TSH_ZL);
 
+impl Int1ThresholdRegisterZL {
+    /// Bit position of [`threshold`](Self::threshold).
+    pub const THRESHOLD_SHIFT: u8 = Self::THRESHOLD_OFFSET as u8;
+    /// Bit mask of [`threshold`](Self::threshold).
+    pub const THRESHOLD_MASK: u8 = field_mask(Self::THRESHOLD_BITS, Self::THRESHOLD_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 1] = [FieldDescriptor::new(
+        "threshold",
+        Self::THRESHOLD_SHIFT,
+        Self::THRESHOLD_BITS as u8,
+        FieldAccess::ReadWrite,
+    )];
+}
+
 /// [`INT1_DURATION`](RegisterAddress::INT1_DURATION) (38h)
 #[bitfield(u8, order = Msb)]
 #[derive(PartialEq, Eq)]
@@ -720,3 +1896,31 @@ pub struct Int1DurationRegister {
 }
 
 writable_register!(Int1DurationRegister, RegisterAddress::INT1_DURATION);
+
+impl Int1DurationRegister {
+    /// Bit position of [`wait`](Self::wait).
+    pub const WAIT_SHIFT: u8 = Self::WAIT_OFFSET as u8;
+    /// Bit mask of [`wait`](Self::wait).
+    pub const WAIT_MASK: u8 = field_mask(Self::WAIT_BITS, Self::WAIT_OFFSET);
+
+    /// Bit position of [`duration`](Self::duration).
+    pub const DURATION_SHIFT: u8 = Self::DURATION_OFFSET as u8;
+    /// Bit mask of [`duration`](Self::duration).
+    pub const DURATION_MASK: u8 = field_mask(Self::DURATION_BITS, Self::DURATION_OFFSET);
+
+    /// Descriptors of the fields, from the most significant bit.
+    pub const FIELDS: [FieldDescriptor; 2] = [
+        FieldDescriptor::new(
+            "wait",
+            Self::WAIT_SHIFT,
+            Self::WAIT_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+        FieldDescriptor::new(
+            "duration",
+            Self::DURATION_SHIFT,
+            Self::DURATION_BITS as u8,
+            FieldAccess::ReadWrite,
+        ),
+    ];
+}
@@ -0,0 +1,147 @@
+//! Instrumentation of register access.
+
+use crate::{RegisterAddress, RegisterBus};
+
+/// Per-register read and write counts, see [`Instrumented`].
+///
+/// Registers covered by a burst transfer are counted individually, so redundant polling
+/// (e.g. of [`STATUS_REG`](RegisterAddress::STATUS_REG)) stands out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccessStats {
+    reads: [u32; Self::SLOTS],
+    writes: [u32; Self::SLOTS],
+    read_transactions: u32,
+    write_transactions: u32,
+}
+
+impl AccessStats {
+    /// The number of counters, covering all 6-bit register addresses.
+    const SLOTS: usize = 64;
+
+    /// Creates empty statistics.
+    pub const fn new() -> Self {
+        Self {
+            reads: [0; Self::SLOTS],
+            writes: [0; Self::SLOTS],
+            read_transactions: 0,
+            write_transactions: 0,
+        }
+    }
+
+    /// Returns the number of times the register was read.
+    pub const fn reads(&self, register: RegisterAddress) -> u32 {
+        self.reads[register.addr() as usize]
+    }
+
+    /// Returns the number of times the register was written.
+    pub const fn writes(&self, register: RegisterAddress) -> u32 {
+        self.writes[register.addr() as usize]
+    }
+
+    /// Returns the number of read transactions.
+    pub const fn read_transactions(&self) -> u32 {
+        self.read_transactions
+    }
+
+    /// Returns the number of write transactions.
+    pub const fn write_transactions(&self) -> u32 {
+        self.write_transactions
+    }
+
+    /// Iterates the registers that were accessed, with their read and write counts,
+    /// in address order.
+    pub fn iter(&self) -> impl Iterator<Item = (RegisterAddress, u32, u32)> + '_ {
+        RegisterAddress::ALL
+            .into_iter()
+            .map(|register| (register, self.reads(register), self.writes(register)))
+            .filter(|(_, reads, writes)| *reads != 0 || *writes != 0)
+    }
+
+    /// Returns the most frequently read register, if any register was read.
+    pub fn most_read(&self) -> Option<(RegisterAddress, u32)> {
+        RegisterAddress::ALL
+            .into_iter()
+            .map(|register| (register, self.reads(register)))
+            .filter(|(_, reads)| *reads != 0)
+            .max_by_key(|(_, reads)| *reads)
+    }
+
+    /// Clears all counts.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn count(counts: &mut [u32; Self::SLOTS], start: RegisterAddress, len: usize) {
+        let start = usize::from(start.addr());
+        let end = (start + len).min(Self::SLOTS);
+        for count in &mut counts[start..end] {
+            *count = count.saturating_add(1);
+        }
+    }
+}
+
+impl Default for AccessStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`RegisterBus`] wrapper recording [`AccessStats`] for every transfer.
+#[derive(Debug, Clone)]
+pub struct Instrumented<B> {
+    bus: B,
+    stats: AccessStats,
+}
+
+impl<B> Instrumented<B> {
+    /// Wraps a bus.
+    pub const fn new(bus: B) -> Self {
+        Self {
+            bus,
+            stats: AccessStats::new(),
+        }
+    }
+
+    /// Returns the statistics collected so far.
+    pub const fn stats(&self) -> &AccessStats {
+        &self.stats
+    }
+
+    /// Clears the statistics.
+    pub fn reset_stats(&mut self) {
+        self.stats.reset();
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+}
+
+impl<B> RegisterBus for Instrumented<B>
+where
+    B: RegisterBus,
+{
+    type Error = B::Error;
+
+    fn read_registers(
+        &mut self,
+        start: RegisterAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.stats.read_transactions = self.stats.read_transactions.saturating_add(1);
+        AccessStats::count(&mut self.stats.reads, start, buffer.len());
+        self.bus.read_registers(start, buffer)
+    }
+
+    fn write_registers(
+        &mut self,
+        start: RegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.stats.write_transactions = self.stats.write_transactions.saturating_add(1);
+        AccessStats::count(&mut self.stats.writes, start, values.len());
+        self.bus.write_registers(start, values)
+    }
+}
@@ -0,0 +1,492 @@
+//! Helpers for configuring the INT1 interrupt generator.
+
+use crate::{
+    Config, Int1ConfigurationRegister, Int1DurationRegister, Int1SourceRegisterA,
+    Int1ThresholdRegisterXH, Int1ThresholdRegisterXL, Int1ThresholdRegisterYH,
+    Int1ThresholdRegisterYL, Int1ThresholdRegisterZH, Int1ThresholdRegisterZL, OutputDataRate,
+    RegisterAddress, Sensitivity,
+};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+/// The 15-bit interrupt thresholds of all three axes, in digits.
+///
+/// The thresholds occupy the contiguous registers `INT1_TSH_XH` (32h) through `INT1_TSH_ZL` (37h)
+/// and can be written in a single auto-increment transfer using [`to_bytes`](Self::to_bytes).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Int1Thresholds {
+    /// X-axis threshold.
+    pub x: u16,
+    /// Y-axis threshold.
+    pub y: u16,
+    /// Z-axis threshold.
+    pub z: u16,
+}
+
+impl Int1Thresholds {
+    /// The first register of the threshold block, [`INT1_TSH_XH`](RegisterAddress::INT1_TSH_XH).
+    pub const START_ADDRESS: RegisterAddress = RegisterAddress::INT1_TSH_XH;
+
+    /// The number of bytes in the threshold block.
+    pub const LEN: usize = 6;
+
+    /// The largest threshold value.
+    pub const MAX: u16 = 0x7FFF;
+
+    /// Creates new thresholds. Values are limited to [`MAX`](Self::MAX).
+    pub const fn new(x: u16, y: u16, z: u16) -> Self {
+        Self {
+            x: Self::clamp(x),
+            y: Self::clamp(y),
+            z: Self::clamp(z),
+        }
+    }
+
+    /// Encodes the register bytes in address order, i.e. `XH`, `XL`, `YH`, `YL`, `ZH`, `ZL`.
+    ///
+    /// Values are limited to [`MAX`](Self::MAX).
+    pub const fn to_bytes(&self) -> [u8; 6] {
+        let [xh, xl] = Self::clamp(self.x).to_be_bytes();
+        let [yh, yl] = Self::clamp(self.y).to_be_bytes();
+        let [zh, zl] = Self::clamp(self.z).to_be_bytes();
+        [xh, xl, yh, yl, zh, zl]
+    }
+
+    /// Decodes the register bytes in address order.
+    ///
+    /// The unused most significant bit of each high byte is ignored.
+    pub const fn from_bytes(bytes: [u8; 6]) -> Self {
+        Self {
+            x: u16::from_be_bytes([bytes[0], bytes[1]]) & Self::MAX,
+            y: u16::from_be_bytes([bytes[2], bytes[3]]) & Self::MAX,
+            z: u16::from_be_bytes([bytes[4], bytes[5]]) & Self::MAX,
+        }
+    }
+
+    const fn clamp(value: u16) -> u16 {
+        if value > Self::MAX {
+            Self::MAX
+        } else {
+            value
+        }
+    }
+}
+
+impl Config {
+    /// Returns the INT1 thresholds.
+    pub const fn int1_thresholds(&self) -> Int1Thresholds {
+        Int1Thresholds::from_bytes([
+            self.int1_tsh_xh.into_bits(),
+            self.int1_tsh_xl.into_bits(),
+            self.int1_tsh_yh.into_bits(),
+            self.int1_tsh_yl.into_bits(),
+            self.int1_tsh_zh.into_bits(),
+            self.int1_tsh_zl.into_bits(),
+        ])
+    }
+
+    /// Sets the INT1 thresholds.
+    pub fn set_int1_thresholds(&mut self, thresholds: Int1Thresholds) {
+        *self = self.with_int1_thresholds(thresholds);
+    }
+
+    /// Sets the INT1 thresholds.
+    #[must_use]
+    pub const fn with_int1_thresholds(mut self, thresholds: Int1Thresholds) -> Self {
+        let [xh, xl, yh, yl, zh, zl] = thresholds.to_bytes();
+        self.int1_tsh_xh = Int1ThresholdRegisterXH::from_bits(xh);
+        self.int1_tsh_xl = Int1ThresholdRegisterXL::from_bits(xl);
+        self.int1_tsh_yh = Int1ThresholdRegisterYH::from_bits(yh);
+        self.int1_tsh_yl = Int1ThresholdRegisterYL::from_bits(yl);
+        self.int1_tsh_zh = Int1ThresholdRegisterZH::from_bits(zh);
+        self.int1_tsh_zl = Int1ThresholdRegisterZL::from_bits(zl);
+        self
+    }
+}
+
+/// A consistent set of `INT1_CFG`, `INT1_TSH_*` and `INT1_DURATION` values for a common
+/// detection task. Apply it using [`Config::with_int1_preset`].
+///
+/// Presets convert thresholds and durations using the full scale and output data rate
+/// passed to the constructor; they must match the sensor configuration. The presets make
+/// the following assumptions:
+///
+/// * Thresholds apply to all three axes and are rounded down to whole digits.
+/// * Durations are rounded down to whole sample periods and limited to 127 samples,
+///   i.e. about 1.3 s at 95 Hz and 0.17 s at 760 Hz.
+/// * Interrupts are not latched, and the data source selected by
+///   [`ControlRegister5::int1_sel`](crate::ControlRegister5::int1_sel) is left unchanged.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Int1Preset {
+    /// The interrupt configuration.
+    pub cfg: Int1ConfigurationRegister,
+    /// The interrupt thresholds.
+    pub thresholds: Int1Thresholds,
+    /// The interrupt duration.
+    pub duration: Int1DurationRegister,
+}
+
+impl Int1Preset {
+    /// The largest duration in samples.
+    pub const MAX_DURATION_SAMPLES: u8 = 0x7F;
+
+    /// Detects rotation: the interrupt fires once the angular rate of any axis stays above
+    /// `threshold_dps` for at least `min_duration_ms`, and falls as soon as it drops below.
+    pub fn rotation_detect(
+        threshold_dps: f32,
+        min_duration_ms: u16,
+        sensitivity: Sensitivity,
+        odr: OutputDataRate,
+    ) -> Self {
+        let threshold = Self::threshold_digits(threshold_dps, sensitivity);
+        Self {
+            cfg: Int1ConfigurationRegister::HIGH_EVENTS,
+            thresholds: Int1Thresholds::new(threshold, threshold, threshold),
+            duration: Int1DurationRegister::new()
+                .with_duration(Self::duration_samples(min_duration_ms, odr)),
+        }
+    }
+
+    /// Detects the absence of motion: the interrupt fires once the angular rate of all axes
+    /// stays below `threshold_dps` for at least `duration_ms`.
+    ///
+    /// The wait bit is set, so the interrupt only falls after motion persisted for the
+    /// same duration, which suppresses toggling around the threshold.
+    pub fn no_motion(
+        threshold_dps: f32,
+        duration_ms: u16,
+        sensitivity: Sensitivity,
+        odr: OutputDataRate,
+    ) -> Self {
+        let threshold = Self::threshold_digits(threshold_dps, sensitivity);
+        Self {
+            cfg: Int1ConfigurationRegister::LOW_EVENTS.with_trigger_mode(TriggerMode::AllEvents),
+            thresholds: Int1Thresholds::new(threshold, threshold, threshold),
+            duration: Int1DurationRegister::new()
+                .with_wait(true)
+                .with_duration(Self::duration_samples(duration_ms, odr)),
+        }
+    }
+
+    /// Converts a threshold to digits, limited to [`Int1Thresholds::MAX`].
+    fn threshold_digits(threshold_dps: f32, sensitivity: Sensitivity) -> u16 {
+        let digits = threshold_dps * 1000.0 / sensitivity.mdps_per_digit();
+        if digits <= 0.0 {
+            0
+        } else if digits >= Int1Thresholds::MAX as f32 {
+            Int1Thresholds::MAX
+        } else {
+            digits as u16
+        }
+    }
+
+    /// Converts a duration to samples, limited to [`MAX_DURATION_SAMPLES`](Self::MAX_DURATION_SAMPLES).
+    const fn duration_samples(duration_ms: u16, odr: OutputDataRate) -> u8 {
+        let samples = duration_ms as u32 * odr.hz() as u32 / 1000;
+        if samples > Self::MAX_DURATION_SAMPLES as u32 {
+            Self::MAX_DURATION_SAMPLES
+        } else {
+            samples as u8
+        }
+    }
+}
+
+impl Config {
+    /// Applies an INT1 preset and routes the interrupt to the INT1 pin.
+    #[must_use]
+    pub const fn with_int1_preset(mut self, preset: Int1Preset) -> Self {
+        self = self.with_int1_thresholds(preset.thresholds);
+        self.int1_cfg = preset.cfg;
+        self.int1_duration = preset.duration;
+        self.ctrl_reg3 = self.ctrl_reg3.with_i1int1(true);
+        self
+    }
+}
+
+/// How the enabled INT1 events are combined and whether the interrupt is latched,
+/// i.e. the `AOI` and `LIR` bits of [`Int1ConfigurationRegister`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TriggerMode {
+    /// The interrupt is active while any enabled event is active (OR combination).
+    /// It falls as soon as no event is active anymore.
+    #[default]
+    AnyEvent,
+    /// The interrupt is active while all enabled events are active (AND combination),
+    /// e.g. for detecting no motion on all axes using low events.
+    AllEvents,
+    /// Like [`AnyEvent`](Self::AnyEvent), but the interrupt stays active until
+    /// [`INT1_SRC`](RegisterAddress::INT1_SRC) is read.
+    AnyEventLatched,
+    /// Like [`AllEvents`](Self::AllEvents), but the interrupt stays active until
+    /// [`INT1_SRC`](RegisterAddress::INT1_SRC) is read.
+    AllEventsLatched,
+}
+
+impl TriggerMode {
+    /// Determines whether all enabled events must be active, i.e. the `AOI` bit.
+    pub const fn is_all_events(self) -> bool {
+        matches!(self, TriggerMode::AllEvents | TriggerMode::AllEventsLatched)
+    }
+
+    /// Determines whether the interrupt is latched, i.e. the `LIR` bit.
+    pub const fn is_latched(self) -> bool {
+        matches!(
+            self,
+            TriggerMode::AnyEventLatched | TriggerMode::AllEventsLatched
+        )
+    }
+}
+
+impl Int1ConfigurationRegister {
+    /// The interrupt-enable bits of all high events (`XHIE`, `YHIE`, `ZHIE`).
+    pub const HIGH_EVENTS: Self = Self::new().with_xhie(true).with_yhie(true).with_zhie(true);
+
+    /// The interrupt-enable bits of all low events (`XLIE`, `YLIE`, `ZLIE`).
+    pub const LOW_EVENTS: Self = Self::new().with_xlie(true).with_ylie(true).with_zlie(true);
+
+    /// The interrupt-enable bits of all events.
+    pub const ALL_EVENTS: Self = Self::from_bits(Self::EVENTS_MASK);
+
+    /// The mask of the interrupt-enable bits.
+    const EVENTS_MASK: u8 = Self::HIGH_EVENTS.into_bits() | Self::LOW_EVENTS.into_bits();
+
+    /// Returns the combination and latching of interrupt events, i.e. the `AOI` and `LIR` bits.
+    pub const fn trigger_mode(&self) -> TriggerMode {
+        match (self.aoi(), self.lir()) {
+            (false, false) => TriggerMode::AnyEvent,
+            (true, false) => TriggerMode::AllEvents,
+            (false, true) => TriggerMode::AnyEventLatched,
+            (true, true) => TriggerMode::AllEventsLatched,
+        }
+    }
+
+    /// Sets the combination and latching of interrupt events, i.e. the `AOI` and `LIR` bits.
+    #[must_use]
+    pub const fn with_trigger_mode(self, mode: TriggerMode) -> Self {
+        self.with_aoi(mode.is_all_events())
+            .with_lir(mode.is_latched())
+    }
+
+    /// Sets the combination and latching of interrupt events, i.e. the `AOI` and `LIR` bits.
+    pub fn set_trigger_mode(&mut self, mode: TriggerMode) {
+        *self = self.with_trigger_mode(mode);
+    }
+
+    const fn combine(self, events: u8) -> Self {
+        Self::from_bits((self.into_bits() & !Self::EVENTS_MASK) | (events & Self::EVENTS_MASK))
+    }
+}
+
+/// Enables the interrupt events of both operands.
+///
+/// The `AOI` and `LIR` bits are taken from the left-hand side.
+impl BitOr for Int1ConfigurationRegister {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.combine(self.into_bits() | rhs.into_bits())
+    }
+}
+
+impl BitOrAssign for Int1ConfigurationRegister {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+/// Keeps the interrupt events enabled in both operands, e.g.
+/// `config & !Int1ConfigurationRegister::LOW_EVENTS` to disable all low events.
+///
+/// The `AOI` and `LIR` bits are taken from the left-hand side.
+impl BitAnd for Int1ConfigurationRegister {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.combine(self.into_bits() & rhs.into_bits())
+    }
+}
+
+impl BitAndAssign for Int1ConfigurationRegister {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+/// Inverts the interrupt-enable bits, keeping the `AOI` and `LIR` bits.
+impl Not for Int1ConfigurationRegister {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.combine(!self.into_bits())
+    }
+}
+
+/// The persistence required by a [`Debouncer`] before an event is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Persistence {
+    /// The event must be active in this many consecutive reads.
+    Reads(u32),
+    /// The event must remain active for at least this many microseconds.
+    DurationUs(u64),
+}
+
+/// Debounces INT1 events in software, e.g. for durations beyond the
+/// [`MAX_DURATION_SAMPLES`](Int1Preset::MAX_DURATION_SAMPLES) of the hardware counter.
+///
+/// Pass every read of [`INT1_SRC`](RegisterAddress::INT1_SRC) to
+/// [`observe`](Self::observe). An event is reported once when it has persisted long enough;
+/// it is reported again only after [`ia`](Int1SourceRegisterA::ia) was cleared in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Debouncer {
+    persistence: Persistence,
+    reads: u32,
+    since_us: Option<u64>,
+    reported: bool,
+}
+
+impl Debouncer {
+    /// Creates a debouncer with the specified persistence.
+    pub const fn new(persistence: Persistence) -> Self {
+        Self {
+            persistence,
+            reads: 0,
+            since_us: None,
+            reported: false,
+        }
+    }
+
+    /// Creates a debouncer requiring an event in `reads` consecutive reads.
+    pub const fn reads(reads: u32) -> Self {
+        Self::new(Persistence::Reads(reads))
+    }
+
+    /// Creates a debouncer requiring an event to last for `duration_us` microseconds.
+    pub const fn duration_us(duration_us: u64) -> Self {
+        Self::new(Persistence::DurationUs(duration_us))
+    }
+
+    /// Returns the required persistence.
+    pub const fn persistence(&self) -> Persistence {
+        self.persistence
+    }
+
+    /// Processes a read of the interrupt source register, returning it if the event has
+    /// just persisted long enough.
+    pub fn observe(
+        &mut self,
+        now_us: u64,
+        source: Int1SourceRegisterA,
+    ) -> Option<Int1SourceRegisterA> {
+        if !source.ia() {
+            self.reset();
+            return None;
+        }
+
+        self.reads = self.reads.saturating_add(1);
+        let since_us = *self.since_us.get_or_insert(now_us);
+        if self.reported {
+            return None;
+        }
+
+        let persisted = match self.persistence {
+            Persistence::Reads(reads) => self.reads >= reads,
+            Persistence::DurationUs(duration_us) => now_us.saturating_sub(since_us) >= duration_us,
+        };
+        self.reported = persisted;
+        persisted.then_some(source)
+    }
+
+    /// Determines whether the current event has been reported and is still active.
+    pub const fn is_reported(&self) -> bool {
+        self.reported
+    }
+
+    /// Forgets the current event.
+    pub fn reset(&mut self) {
+        self.reads = 0;
+        self.since_us = None;
+        self.reported = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds_encode_in_address_order() {
+        let thresholds = Int1Thresholds::new(0x0102, 0x7FFF, 0xFFFF);
+        assert_eq!(thresholds.z, Int1Thresholds::MAX);
+        assert_eq!(thresholds.to_bytes(), [0x01, 0x02, 0x7F, 0xFF, 0x7F, 0xFF]);
+        assert_eq!(
+            Int1Thresholds::from_bytes(thresholds.to_bytes()),
+            thresholds
+        );
+    }
+
+    #[test]
+    fn thresholds_ignore_the_unused_bit() {
+        let thresholds = Int1Thresholds::from_bytes([0x81, 0x02, 0x80, 0x00, 0xFF, 0xFF]);
+        assert_eq!(thresholds, Int1Thresholds::new(0x0102, 0, 0x7FFF));
+    }
+
+    #[test]
+    fn config_round_trips_thresholds() {
+        let thresholds = Int1Thresholds::new(1, 0x1234, 0x7FFF);
+        let config = Config::new().with_int1_thresholds(thresholds);
+        assert_eq!(config.int1_thresholds(), thresholds);
+        assert_eq!(config.int1_tsh_yh.into_bits(), 0x12);
+        assert_eq!(config.int1_tsh_yl.into_bits(), 0x34);
+    }
+
+    #[test]
+    fn rotation_detect_converts_units() {
+        let preset =
+            Int1Preset::rotation_detect(10.0, 100, Sensitivity::D250, OutputDataRate::Hz95);
+        assert_eq!(preset.cfg, Int1ConfigurationRegister::HIGH_EVENTS);
+        assert_eq!(preset.thresholds, Int1Thresholds::new(1142, 1142, 1142));
+        assert_eq!(preset.duration.duration(), 9);
+        assert!(!preset.duration.wait());
+    }
+
+    #[test]
+    fn presets_clamp_thresholds_and_durations() {
+        let preset =
+            Int1Preset::rotation_detect(2000.0, 2000, Sensitivity::D250, OutputDataRate::Hz760);
+        assert_eq!(preset.thresholds.x, Int1Thresholds::MAX);
+        assert_eq!(preset.duration.duration(), Int1Preset::MAX_DURATION_SAMPLES);
+
+        let preset = Int1Preset::no_motion(-1.0, 0, Sensitivity::D2000, OutputDataRate::Hz95);
+        assert_eq!(preset.thresholds, Int1Thresholds::new(0, 0, 0));
+        assert_eq!(preset.duration.duration(), 0);
+    }
+
+    #[test]
+    fn no_motion_requires_all_axes() {
+        let preset = Int1Preset::no_motion(1.0, 500, Sensitivity::D500, OutputDataRate::Hz190);
+        assert_eq!(
+            preset.cfg,
+            Int1ConfigurationRegister::LOW_EVENTS.with_trigger_mode(TriggerMode::AllEvents)
+        );
+        assert!(preset.cfg.aoi());
+        assert!(!preset.cfg.lir());
+        assert_eq!(preset.thresholds.x, 57);
+        assert_eq!(preset.duration.duration(), 95);
+        assert!(preset.duration.wait());
+    }
+
+    #[test]
+    fn preset_routes_to_int1() {
+        let preset =
+            Int1Preset::rotation_detect(10.0, 100, Sensitivity::D250, OutputDataRate::Hz95);
+        let config = Config::new().with_int1_preset(preset);
+        assert_eq!(config.int1_cfg, preset.cfg);
+        assert_eq!(config.int1_duration, preset.duration);
+        assert_eq!(config.int1_thresholds(), preset.thresholds);
+        assert!(config.ctrl_reg3.i1int1());
+    }
+}
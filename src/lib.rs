@@ -5,10 +5,17 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 #![no_std]
-#![forbid(unsafe_code)]
+// The `ffi` feature requires `#[no_mangle]`, which is covered by the `unsafe_code` lint.
+#![cfg_attr(not(feature = "ffi"), forbid(unsafe_code))]
+#![cfg_attr(feature = "ffi", deny(unsafe_code))]
 // Enables the `doc_cfg` feature when the `docsrs` configuration attribute is defined.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Exports commonly used traits.
 pub mod prelude {
     pub use crate::{Register, WritableRegister};
@@ -21,7 +28,11 @@ pub mod prelude {
 
 macro_rules! readable_register {
     ($type:ident, $addr:expr) => {
-        impl $crate::Register for $type {}
+        impl $crate::sealed::Sealed for $type {}
+        impl $crate::Register for $type {
+            const ADDRESS: $crate::RegisterAddress = $addr;
+            const FIELDS: &'static [$crate::FieldDescriptor] = &$type::FIELDS;
+        }
         impl $crate::prelude::HardwareRegister<$crate::prelude::R1> for $type {}
 
         impl
@@ -78,18 +89,158 @@ macro_rules! writable_register {
     };
 }
 
+pub mod compat;
+pub mod consts;
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub mod ffi;
+#[cfg(feature = "test-vectors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+pub mod test_vectors;
+
+mod angle;
+mod autorange;
+mod buffer;
+mod burst;
+mod bus;
+mod calibration;
+mod clock;
+mod config;
 mod conversions;
+mod detect;
+mod device;
+mod drdy;
+mod error_code;
+mod filter;
+mod freshness;
 mod gyro;
+mod instrument;
+mod int1;
+#[cfg(feature = "defmt")]
+mod log;
+#[cfg(feature = "math")]
+mod math;
+mod measurement;
+mod multi;
+mod output;
+mod parse;
+mod pipeline;
+mod power;
+mod recorder;
+mod reflect;
+mod register_file;
+mod replay;
+mod retry;
+mod schedule;
+mod sink;
+mod stats;
+mod telemetry;
+mod temperature;
 mod types;
-
+mod units;
+mod verify;
+
+pub use angle::*;
+pub use autorange::*;
+pub use buffer::*;
+pub use burst::*;
+pub use bus::*;
+pub use calibration::*;
+pub use clock::*;
+pub use config::*;
+pub use detect::*;
+pub use device::*;
+pub use drdy::*;
+pub use error_code::*;
+pub use filter::*;
+pub use freshness::*;
 pub use gyro::*;
+pub use instrument::*;
+pub use int1::*;
+#[cfg(feature = "math")]
+#[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+pub use math::*;
+pub use measurement::*;
+pub use multi::*;
+pub use output::*;
+pub use parse::*;
+pub use pipeline::*;
+pub use power::*;
+pub use recorder::*;
+pub use reflect::*;
+pub use register_file::*;
+pub use replay::*;
+pub use retry::*;
+pub use schedule::*;
+pub use sink::*;
+pub use stats::*;
+pub use telemetry::*;
+pub use temperature::*;
 pub use types::*;
+pub use units::*;
+pub use verify::*;
 
 /// A sensor register.
-pub trait Register: prelude::I2CRegister8<prelude::DeviceAddress7> + From<u8> + Into<u8> {}
+///
+/// This trait is sealed and implemented for every register type of this crate.
+pub trait Register:
+    sealed::Sealed + prelude::I2CRegister8<prelude::DeviceAddress7> + Copy + From<u8> + Into<u8>
+{
+    /// The address of the register.
+    const ADDRESS: RegisterAddress;
+
+    /// The fields of the register, from the most significant bit. Reserved bits are not
+    /// described.
+    const FIELDS: &'static [FieldDescriptor];
+
+    /// Creates the register from its byte value.
+    #[inline]
+    fn from_byte(value: u8) -> Self {
+        value.into()
+    }
+
+    /// Returns the byte value of the register.
+    #[inline]
+    fn to_byte(self) -> u8 {
+        self.into()
+    }
+
+    /// Returns the bits deviating from the documented reset value.
+    ///
+    /// Returns `None` for registers without a documented reset value, such as output registers.
+    /// See [`RegisterAddress::reset_value`].
+    fn reset_deviation(&self) -> Option<u8> {
+        Self::ADDRESS
+            .reset_value()
+            .map(|reset| reset ^ (*self).to_byte())
+    }
+
+    /// Determines whether the register holds its documented reset value.
+    ///
+    /// Always `false` for registers without a documented reset value.
+    fn is_default(&self) -> bool {
+        self.reset_deviation() == Some(0)
+    }
+
+    /// Iterates the fields deviating from the documented reset value, from the most
+    /// significant bit.
+    ///
+    /// Yields nothing for registers without a documented reset value. Deviating reserved bits
+    /// are not reported, see [`reset_deviation`](Self::reset_deviation).
+    fn deviating_fields(&self) -> DeviatingFields {
+        DeviatingFields::new(Self::FIELDS, self.reset_deviation().unwrap_or(0))
+    }
+}
 
 /// A writable sensor register.
+///
+/// This trait is sealed and implemented for every writable register type of this crate.
 pub trait WritableRegister:
     prelude::WritableI2CRegister8<prelude::DeviceAddress7> + Register
 {
 }
+
+pub(crate) mod sealed {
+    /// Prevents implementations of [`Register`](crate::Register) outside of this crate.
+    pub trait Sealed {}
+}
@@ -0,0 +1,34 @@
+//! Logging helpers for `defmt`.
+
+/// Logs a [`Measurement`](crate::Measurement) using `defmt` with compact encodings.
+///
+/// The log level defaults to `debug` and can be selected as the first argument. An optional
+/// message prefix can be provided as a string literal. The calling crate must depend on `defmt`.
+///
+/// ```ignore
+/// log_measurement!(measurement);
+/// log_measurement!(info, measurement);
+/// log_measurement!(trace, "fifo", measurement);
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+macro_rules! log_measurement {
+    ($measurement:expr) => {
+        $crate::log_measurement!(debug, $measurement)
+    };
+    ($level:ident, $measurement:expr) => {
+        $crate::log_measurement!($level, "gyro", $measurement)
+    };
+    ($level:ident, $prefix:literal, $measurement:expr) => {{
+        let measurement: &$crate::Measurement = &$measurement;
+        ::defmt::$level!(
+            "{=str}: {=i16} {=i16} {=i16} {=i8} {=u8:#04x}",
+            $prefix,
+            measurement.rate.x,
+            measurement.rate.y,
+            measurement.rate.z,
+            measurement.temp,
+            measurement.status.into_bits()
+        );
+    }};
+}
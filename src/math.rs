@@ -0,0 +1,138 @@
+//! Building blocks for orientation estimation.
+
+use crate::units::sqrt;
+use crate::{AngularRate, AngularRateRaw, CalibrationProfile, Sensitivity};
+use core::ops::Mul;
+
+/// A rotation quaternion `w + xi + yj + zk`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Quaternion {
+    /// Scalar part.
+    pub w: f32,
+    /// X component of the vector part.
+    pub x: f32,
+    /// Y component of the vector part.
+    pub y: f32,
+    /// Z component of the vector part.
+    pub z: f32,
+}
+
+impl Quaternion {
+    /// The identity rotation.
+    pub const IDENTITY: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+
+    /// Creates a quaternion from its components.
+    pub const fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Returns the quaternion scaled to unit length.
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let norm = sqrt(self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z);
+        Self::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// The Hamilton product `self * rhs`, i.e. the rotation `rhs` followed by `self`
+/// in the global frame, or `self` followed by `rhs` in the body frame.
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        }
+    }
+}
+
+/// Converts an angular rate held for `dt` seconds into an incremental rotation.
+///
+/// Uses the small-angle approximation `q ≈ [1, θx/2, θy/2, θz/2]` (normalized), where `θ` is
+/// the rotation in radians during `dt`. The error is below 0.1% for rotations of up to
+/// roughly 5° per step, e.g. 2000 dps sampled at 380 Hz or faster.
+///
+/// Apply the increment in the body frame, i.e. `orientation = orientation * increment`.
+#[must_use]
+pub fn small_angle_quaternion(rate: AngularRate, dt: f32) -> Quaternion {
+    let [x, y, z] = rate.to_rad_per_sec();
+    let half_dt = 0.5 * dt;
+    Quaternion::new(1.0, x * half_dt, y * half_dt, z * half_dt).normalized()
+}
+
+/// Propagates an orientation from successive angular rate measurements (gyro-only strapdown
+/// integration).
+///
+/// Each measurement is corrected using a [`CalibrationProfile`] and integrated using
+/// [`small_angle_quaternion`] in the body frame.
+///
+/// This is meant as a starting point rather than an attitude estimator: without an absolute
+/// reference such as an accelerometer or magnetometer, any residual bias is integrated as well
+/// and the orientation drifts without bound, even after calibration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Strapdown {
+    orientation: Quaternion,
+    calibration: CalibrationProfile,
+}
+
+impl Strapdown {
+    /// Creates a new integrator starting at the identity orientation without correction.
+    pub const fn new() -> Self {
+        Self {
+            orientation: Quaternion::IDENTITY,
+            calibration: CalibrationProfile::IDENTITY,
+        }
+    }
+
+    /// Sets the calibration applied to every measurement, e.g. the estimated bias.
+    #[must_use]
+    pub const fn with_calibration(mut self, calibration: CalibrationProfile) -> Self {
+        self.calibration = calibration;
+        self
+    }
+
+    /// Sets the initial orientation.
+    #[must_use]
+    pub const fn with_orientation(mut self, orientation: Quaternion) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Integrates an angular rate held for `dt` seconds.
+    pub fn update(&mut self, rate: AngularRate, dt: f32) {
+        let increment = small_angle_quaternion(self.calibration.apply(rate), dt);
+        self.orientation = (self.orientation * increment).normalized();
+    }
+
+    /// Scales and integrates a raw reading held for `dt` seconds.
+    pub fn update_raw(&mut self, raw: AngularRateRaw, sensitivity: Sensitivity, dt: f32) {
+        self.update(AngularRate::from_raw(raw, sensitivity), dt);
+    }
+
+    /// Returns the current orientation.
+    pub const fn orientation(&self) -> Quaternion {
+        self.orientation
+    }
+
+    /// Resets the orientation to the identity, keeping the calibration.
+    pub fn reset(&mut self) {
+        self.orientation = Quaternion::IDENTITY;
+    }
+}
+
+impl Default for Strapdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,290 @@
+//! Measurement types decoded from burst reads of the output registers.
+
+use crate::{RegisterAddress, StatusRegister};
+
+/// Raw angular rate reading of all three axes, in digits.
+///
+/// Sensitivity in mdps/digit as well as error depend on [`Sensitivity`](crate::Sensitivity)
+/// and [`Bandwidth`](crate::Bandwidth).
+///
+/// The in-memory layout is fixed to X, Y and Z in order, without padding.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct AngularRateRaw {
+    /// X-axis angular rate.
+    pub x: i16,
+    /// Y-axis angular rate.
+    pub y: i16,
+    /// Z-axis angular rate.
+    pub z: i16,
+}
+
+impl AngularRateRaw {
+    /// The first register of the output data block, [`OUT_X_L`](RegisterAddress::OUT_X_L).
+    pub const START_ADDRESS: RegisterAddress = RegisterAddress::OUT_X_L;
+
+    /// The number of bytes in the output data block, `OUT_X_L` (28h) through `OUT_Z_H` (2Dh).
+    pub const LEN: usize = 6;
+
+    /// Creates a new reading from its axis values.
+    pub const fn new(x: i16, y: i16, z: i16) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Decodes the output data block (28h through 2Dh) in register order.
+    ///
+    /// This assumes the default little endian data order, i.e.
+    /// [`ControlRegister4::big_endian`](crate::ControlRegister4::big_endian) being `false`.
+    pub const fn from_le_bytes(bytes: [u8; 6]) -> Self {
+        Self {
+            x: i16::from_le_bytes([bytes[0], bytes[1]]),
+            y: i16::from_le_bytes([bytes[2], bytes[3]]),
+            z: i16::from_le_bytes([bytes[4], bytes[5]]),
+        }
+    }
+
+    /// Decodes the output data block (28h through 2Dh) in register order when
+    /// [`ControlRegister4::big_endian`](crate::ControlRegister4::big_endian) is `true`,
+    /// i.e. the MSB is stored at the lower address.
+    pub const fn from_be_bytes(bytes: [u8; 6]) -> Self {
+        Self {
+            x: i16::from_be_bytes([bytes[0], bytes[1]]),
+            y: i16::from_be_bytes([bytes[2], bytes[3]]),
+            z: i16::from_be_bytes([bytes[4], bytes[5]]),
+        }
+    }
+
+    /// Encodes the reading into the output data block in register order (little endian).
+    pub const fn to_le_bytes(self) -> [u8; 6] {
+        let x = self.x.to_le_bytes();
+        let y = self.y.to_le_bytes();
+        let z = self.z.to_le_bytes();
+        [x[0], x[1], y[0], y[1], z[0], z[1]]
+    }
+}
+
+/// Logs the reading as `[x, y, z]` using compact `i16` encodings.
+#[cfg(feature = "defmt")]
+impl defmt::Format for AngularRateRaw {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "[{=i16}, {=i16}, {=i16}]", self.x, self.y, self.z);
+    }
+}
+
+/// A coherent sample of angular rate, temperature and status.
+///
+/// All values are obtained from a single auto-increment burst read of
+/// [`OUT_TEMP`](RegisterAddress::OUT_TEMP) (26h) through [`OUT_Z_H`](RegisterAddress::OUT_Z_H) (2Dh),
+/// so the [`status`](Measurement::status) flags describe exactly the rate values read alongside them.
+/// On I²C, set the MSb of the register address to enable auto-increment; on SPI, set the `MS` bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    /// The angular rate reading.
+    pub rate: AngularRateRaw,
+    /// Temperature data (1LSB/deg, two's complement).
+    pub temp: i8,
+    /// The status register at the time of reading.
+    pub status: StatusRegister,
+}
+
+impl Measurement {
+    /// The first register of the burst read, [`OUT_TEMP`](RegisterAddress::OUT_TEMP).
+    pub const START_ADDRESS: RegisterAddress = RegisterAddress::OUT_TEMP;
+
+    /// The number of bytes in the burst read, `OUT_TEMP` (26h) through `OUT_Z_H` (2Dh).
+    pub const LEN: usize = 8;
+
+    /// Decodes a burst read of 26h through 2Dh in register order.
+    ///
+    /// This assumes the default little endian data order, i.e.
+    /// [`ControlRegister4::big_endian`](crate::ControlRegister4::big_endian) being `false`.
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self {
+            temp: bytes[0] as i8,
+            status: StatusRegister::from_bits(bytes[1]),
+            rate: AngularRateRaw::from_le_bytes([
+                bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]),
+        }
+    }
+
+    /// Decodes a burst read of 26h through 2Dh in register order when
+    /// [`ControlRegister4::big_endian`](crate::ControlRegister4::big_endian) is `true`.
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self {
+            temp: bytes[0] as i8,
+            status: StatusRegister::from_bits(bytes[1]),
+            rate: AngularRateRaw::from_be_bytes([
+                bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]),
+        }
+    }
+
+    /// Encodes the measurement into a burst read of 26h through 2Dh in register order
+    /// (little endian).
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        let rate = self.rate.to_le_bytes();
+        [
+            self.temp as u8,
+            self.status.into_bits(),
+            rate[0],
+            rate[1],
+            rate[2],
+            rate[3],
+            rate[4],
+            rate[5],
+        ]
+    }
+
+    /// Indicates whether new data was available for all three axes.
+    pub const fn is_new_data(&self) -> bool {
+        self.status.zyx_da()
+    }
+
+    /// Indicates whether any axis was overwritten before being read.
+    pub const fn is_overrun(&self) -> bool {
+        self.status.zyx_overrun()
+    }
+}
+
+/// Logs the measurement using compact encodings, with the status register as a single byte.
+#[cfg(feature = "defmt")]
+impl defmt::Format for Measurement {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "rate: [{=i16}, {=i16}, {=i16}], temp: {=i8}, status: {=u8:#04x}",
+            self.rate.x,
+            self.rate.y,
+            self.rate.z,
+            self.temp,
+            self.status.into_bits()
+        );
+    }
+}
+
+/// Selects which registers are read along with the angular rate.
+///
+/// The output registers are always read; temperature and status can be added to the
+/// transaction as needed. Since the registers are read in a single auto-increment burst,
+/// the read always extends from [`start_address`](ReadSet::start_address) to
+/// [`OUT_Z_H`](RegisterAddress::OUT_Z_H):
+///
+/// * [`ReadSet::RATE`] reads 6 bytes starting at `OUT_X_L` (28h).
+/// * [`ReadSet::STATUS`] reads 7 bytes starting at `STATUS_REG` (27h).
+/// * [`ReadSet::TEMPERATURE`] reads 8 bytes starting at `OUT_TEMP` (26h).
+///
+/// Reading the temperature therefore always transfers the status register as well;
+/// it is only decoded if [`ReadSet::STATUS`] is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadSet(u8);
+
+impl ReadSet {
+    /// Reads the angular rate only.
+    pub const RATE: Self = Self(0b00);
+
+    /// Reads the temperature in addition to the angular rate.
+    pub const TEMPERATURE: Self = Self(0b01);
+
+    /// Reads the status register in addition to the angular rate.
+    pub const STATUS: Self = Self(0b10);
+
+    /// Reads the angular rate, temperature and status register.
+    pub const ALL: Self = Self(0b11);
+
+    /// Returns the bitmask of this set.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Combines two read sets.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Determines whether all registers in `other` are part of this set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The register address at which the burst read starts.
+    pub const fn start_address(self) -> RegisterAddress {
+        if self.contains(Self::TEMPERATURE) {
+            RegisterAddress::OUT_TEMP
+        } else if self.contains(Self::STATUS) {
+            RegisterAddress::STATUS_REG
+        } else {
+            RegisterAddress::OUT_X_L
+        }
+    }
+
+    /// The number of bytes to read, starting at [`start_address`](ReadSet::start_address).
+    pub const fn burst_len(self) -> usize {
+        (RegisterAddress::OUT_Z_H.addr() - self.start_address().addr()) as usize + 1
+    }
+
+    /// Decodes a burst read of [`burst_len`](ReadSet::burst_len) bytes in register order.
+    ///
+    /// This assumes the default little endian data order. Returns `None` if the
+    /// number of bytes does not match.
+    pub fn decode(self, bytes: &[u8]) -> Option<PartialMeasurement> {
+        if bytes.len() != self.burst_len() {
+            return None;
+        }
+
+        let (head, rate) = bytes.split_at(bytes.len() - AngularRateRaw::LEN);
+        let rate =
+            AngularRateRaw::from_le_bytes([rate[0], rate[1], rate[2], rate[3], rate[4], rate[5]]);
+
+        let temp = if self.contains(Self::TEMPERATURE) {
+            Some(head[0] as i8)
+        } else {
+            None
+        };
+
+        let status = if self.contains(Self::STATUS) {
+            Some(StatusRegister::from_bits(head[head.len() - 1]))
+        } else {
+            None
+        };
+
+        Some(PartialMeasurement { rate, temp, status })
+    }
+}
+
+impl Default for ReadSet {
+    fn default() -> Self {
+        Self::RATE
+    }
+}
+
+impl core::ops::BitOr for ReadSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+/// A measurement decoded according to a [`ReadSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PartialMeasurement {
+    /// The angular rate reading.
+    pub rate: AngularRateRaw,
+    /// Temperature data (1LSB/deg, two's complement), if read.
+    pub temp: Option<i8>,
+    /// The status register at the time of reading, if read.
+    pub status: Option<StatusRegister>,
+}
+
+impl From<Measurement> for PartialMeasurement {
+    fn from(value: Measurement) -> Self {
+        Self {
+            rate: value.rate,
+            temp: Some(value.temp),
+            status: Some(value.status),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Management of two sensors sharing a bus.
+
+use crate::{
+    AngularRateRaw, Config, Measurement, RegisterBus, DEFAULT_DEVICE_ADDRESS,
+    DEFAULT_DEVICE_ADDRESS_ALT,
+};
+
+/// Identifies one of the sensors managed by [`MultiGyro`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GyroSlot {
+    /// The sensor with the SDO pin connected to ground.
+    Primary,
+    /// The sensor with the SDO pin connected to the voltage supply.
+    Secondary,
+}
+
+impl GyroSlot {
+    /// Returns the 7-bit I²C device address of the sensor in this slot.
+    pub const fn device_address(self) -> u8 {
+        match self {
+            GyroSlot::Primary => DEFAULT_DEVICE_ADDRESS,
+            GyroSlot::Secondary => DEFAULT_DEVICE_ADDRESS_ALT,
+        }
+    }
+
+    /// Returns the other slot.
+    pub const fn other(self) -> Self {
+        match self {
+            GyroSlot::Primary => GyroSlot::Secondary,
+            GyroSlot::Secondary => GyroSlot::Primary,
+        }
+    }
+}
+
+/// Two sensors on one I²C bus, one with SDO low and one with SDO high.
+///
+/// Each sensor is accessed through its own [`RegisterBus`], e.g. two handles to a shared bus
+/// bound to [`GyroSlot::device_address`]. Configuration is applied to both sensors register
+/// by register so that they leave power-down mode back to back. Readings are available from
+/// both sensors at once, interleaved or averaged.
+#[derive(Debug)]
+pub struct MultiGyro<B> {
+    primary: B,
+    secondary: B,
+    next: GyroSlot,
+}
+
+impl<B> MultiGyro<B>
+where
+    B: RegisterBus,
+{
+    /// Creates a manager for the sensors behind the two buses.
+    pub const fn new(primary: B, secondary: B) -> Self {
+        Self {
+            primary,
+            secondary,
+            next: GyroSlot::Primary,
+        }
+    }
+
+    /// Returns the bus of a sensor.
+    pub fn bus_mut(&mut self, slot: GyroSlot) -> &mut B {
+        match slot {
+            GyroSlot::Primary => &mut self.primary,
+            GyroSlot::Secondary => &mut self.secondary,
+        }
+    }
+
+    /// Returns the buses of the primary and secondary sensor.
+    pub fn into_inner(self) -> (B, B) {
+        (self.primary, self.secondary)
+    }
+
+    /// Applies the configuration to both sensors.
+    ///
+    /// Follows [`Config::write_sequence`], writing each register to the primary and then the
+    /// secondary sensor before moving on to the next register.
+    pub fn configure(&mut self, config: &Config) -> Result<(), MultiGyroError<B::Error>> {
+        for (register, value) in config.write_sequence() {
+            for slot in [GyroSlot::Primary, GyroSlot::Secondary] {
+                self.bus_mut(slot)
+                    .write_register(register, value)
+                    .map_err(|error| MultiGyroError { slot, error })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the angular rate of one sensor.
+    pub fn read_angular_rate_raw(
+        &mut self,
+        slot: GyroSlot,
+    ) -> Result<AngularRateRaw, MultiGyroError<B::Error>> {
+        self.bus_mut(slot)
+            .read_angular_rate_raw()
+            .map_err(|error| MultiGyroError { slot, error })
+    }
+
+    /// Reads the measurement of one sensor.
+    pub fn read_measurement(
+        &mut self,
+        slot: GyroSlot,
+    ) -> Result<Measurement, MultiGyroError<B::Error>> {
+        self.bus_mut(slot)
+            .read_measurement()
+            .map_err(|error| MultiGyroError { slot, error })
+    }
+
+    /// Reads the angular rate of both sensors back to back, primary first.
+    pub fn read_both(&mut self) -> Result<[AngularRateRaw; 2], MultiGyroError<B::Error>> {
+        Ok([
+            self.read_angular_rate_raw(GyroSlot::Primary)?,
+            self.read_angular_rate_raw(GyroSlot::Secondary)?,
+        ])
+    }
+
+    /// Reads the angular rate of the sensors in turns, starting with the primary sensor.
+    ///
+    /// With both sensors running at the same output data rate and out of phase, this
+    /// doubles the effective sample rate.
+    pub fn read_interleaved(
+        &mut self,
+    ) -> Result<(GyroSlot, AngularRateRaw), MultiGyroError<B::Error>> {
+        let slot = self.next;
+        let rate = self.read_angular_rate_raw(slot)?;
+        self.next = slot.other();
+        Ok((slot, rate))
+    }
+
+    /// Reads both sensors and returns the per-axis average, rounded towards zero.
+    ///
+    /// Both sensors must use the same [`Sensitivity`](crate::Sensitivity).
+    pub fn read_averaged(&mut self) -> Result<AngularRateRaw, MultiGyroError<B::Error>> {
+        let [a, b] = self.read_both()?;
+        Ok(AngularRateRaw::new(
+            average(a.x, b.x),
+            average(a.y, b.y),
+            average(a.z, b.z),
+        ))
+    }
+}
+
+const fn average(a: i16, b: i16) -> i16 {
+    ((a as i32 + b as i32) / 2) as i16
+}
+
+/// A bus error of one of the sensors managed by [`MultiGyro`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiGyroError<E> {
+    /// The sensor whose bus reported the error.
+    pub slot: GyroSlot,
+    /// The bus error.
+    pub error: E,
+}
+
+impl<E> core::fmt::Display for MultiGyroError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.slot {
+            GyroSlot::Primary => write!(f, "primary sensor: {}", self.error),
+            GyroSlot::Secondary => write!(f, "secondary sensor: {}", self.error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<E> std::error::Error for MultiGyroError<E> where E: core::fmt::Debug + core::fmt::Display {}
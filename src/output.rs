@@ -0,0 +1,143 @@
+//! Combined 16-bit output register pairs.
+
+use crate::{OutXHigh, OutXLow, OutYHigh, OutYLow, OutZHigh, OutZLow, RegisterAddress};
+
+macro_rules! output_pair {
+    ($type:ident, $axis:literal, $low:ident, $high:ident, $low_addr:ident, $high_addr:ident) => {
+        #[doc = concat!("Full 16-bit two's complement ", $axis, "-axis angular rate reading, in digits.")]
+        ///
+        #[doc = concat!("Combines [`", stringify!($low), "`] and [`", stringify!($high), "`].")]
+        /// Sensitivity in mdps/digit as well as error depend on [`Sensitivity`](crate::Sensitivity)
+        /// and [`Bandwidth`](crate::Bandwidth).
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $type(i16);
+
+        impl $type {
+            #[doc = concat!("The address of the low byte, [`", stringify!($low_addr), "`](RegisterAddress::", stringify!($low_addr), ").")]
+            pub const LOW_ADDRESS: RegisterAddress = RegisterAddress::$low_addr;
+
+            #[doc = concat!("The address of the high byte, [`", stringify!($high_addr), "`](RegisterAddress::", stringify!($high_addr), ").")]
+            pub const HIGH_ADDRESS: RegisterAddress = RegisterAddress::$high_addr;
+
+            /// Creates a reading from its value.
+            pub const fn new(value: i16) -> Self {
+                Self(value)
+            }
+
+            /// Combines the low and high register bytes.
+            pub const fn from_bytes(low: u8, high: u8) -> Self {
+                Self(i16::from_le_bytes([low, high]))
+            }
+
+            /// Combines the low and high registers.
+            pub const fn from_registers(low: $low, high: $high) -> Self {
+                Self::from_bytes(low.into_bits(), high.into_bits())
+            }
+
+            /// Returns the reading.
+            pub const fn value(&self) -> i16 {
+                self.0
+            }
+
+            /// Returns the low byte.
+            pub const fn low(&self) -> u8 {
+                self.0.to_le_bytes()[0]
+            }
+
+            /// Returns the high byte.
+            pub const fn high(&self) -> u8 {
+                self.0.to_le_bytes()[1]
+            }
+
+            /// Reads the pair using single-byte reads, detecting torn reads.
+            ///
+            /// See [`read_pair_consistent`] for details.
+            pub fn read_consistent<F, E>(read: F, attempts: u8) -> Result<Self, TornReadError<E>>
+            where
+                F: FnMut(RegisterAddress) -> Result<u8, E>,
+            {
+                read_pair_consistent(Self::LOW_ADDRESS, Self::HIGH_ADDRESS, read, attempts)
+                    .map(Self)
+            }
+        }
+
+        impl From<$type> for i16 {
+            fn from(value: $type) -> Self {
+                value.0
+            }
+        }
+
+        impl From<i16> for $type {
+            fn from(value: i16) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<($low, $high)> for $type {
+            fn from(value: ($low, $high)) -> Self {
+                Self::from_registers(value.0, value.1)
+            }
+        }
+    };
+}
+
+output_pair!(OutX, "X", OutXLow, OutXHigh, OUT_X_L, OUT_X_H);
+output_pair!(OutY, "Y", OutYLow, OutYHigh, OUT_Y_L, OUT_Y_H);
+output_pair!(OutZ, "Z", OutZLow, OutZHigh, OUT_Z_L, OUT_Z_H);
+
+/// Reads a 16-bit output register pair using single-byte reads, detecting torn reads.
+///
+/// Without [`block_data_update`](crate::ControlRegister4::block_data_update), the sensor may
+/// update both bytes between two single-byte reads, combining the low byte of one sample with
+/// the high byte of another. This function reads the high byte, the low byte and the high byte
+/// again. If both high bytes match, the low byte belongs to the same sample; otherwise the
+/// read is retried, up to `attempts` times in total (at least once).
+///
+/// Prefer enabling block data update or using burst reads where the bus allows it.
+pub fn read_pair_consistent<F, E>(
+    low: RegisterAddress,
+    high: RegisterAddress,
+    mut read: F,
+    attempts: u8,
+) -> Result<i16, TornReadError<E>>
+where
+    F: FnMut(RegisterAddress) -> Result<u8, E>,
+{
+    for _ in 0..attempts.max(1) {
+        let first = read(high).map_err(TornReadError::Bus)?;
+        let low_byte = read(low).map_err(TornReadError::Bus)?;
+        let second = read(high).map_err(TornReadError::Bus)?;
+        if first == second {
+            return Ok(i16::from_le_bytes([low_byte, second]));
+        }
+    }
+    Err(TornReadError::Inconsistent)
+}
+
+/// An error when reading an output register pair using [`read_pair_consistent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TornReadError<E> {
+    /// The bus reported an error.
+    Bus(E),
+    /// Every attempt observed an update between the reads.
+    Inconsistent,
+}
+
+impl<E> core::fmt::Display for TornReadError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TornReadError::Bus(error) => write!(f, "bus error: {error}"),
+            TornReadError::Inconsistent => {
+                f.write_str("output registers changed during every read attempt")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<E> std::error::Error for TornReadError<E> where E: core::fmt::Debug + core::fmt::Display {}
@@ -0,0 +1,183 @@
+//! String conversions for the configuration enums.
+
+use crate::{Bandwidth, FifoMode, OutputDataRate, Sensitivity};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// An error when parsing a configuration enum from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEnumError {
+    expected: &'static str,
+}
+
+impl ParseEnumError {
+    const fn new(expected: &'static str) -> Self {
+        Self { expected }
+    }
+
+    /// Returns a human-readable list of the accepted values.
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+}
+
+impl Display for ParseEnumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid value, expected one of: {}", self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseEnumError {}
+
+/// Removes a unit suffix (ignoring ASCII case) and surrounding whitespace.
+fn strip_unit<'a>(value: &'a str, unit: &str) -> &'a str {
+    let value = value.trim();
+    let split = value.len().saturating_sub(unit.len());
+    match (value.get(..split), value.get(split..)) {
+        (Some(number), Some(suffix)) if suffix.eq_ignore_ascii_case(unit) => number.trim_end(),
+        _ => value,
+    }
+}
+
+/// Removes a variant prefix (ignoring ASCII case), e.g. `Hz` in `Hz380`.
+fn strip_prefix<'a>(value: &'a str, prefix: &str) -> &'a str {
+    match (value.get(..prefix.len()), value.get(prefix.len()..)) {
+        (Some(head), Some(rest)) if head.eq_ignore_ascii_case(prefix) => rest,
+        _ => value,
+    }
+}
+
+/// Compares two strings, ignoring ASCII case as well as `-` and `_` separators.
+fn matches_name(value: &str, name: &str) -> bool {
+    let mut value = value.bytes().filter(|b| *b != b'-' && *b != b'_');
+    let mut name = name.bytes().filter(|b| *b != b'-' && *b != b'_');
+    loop {
+        match (value.next(), name.next()) {
+            (Some(a), Some(b)) if a.eq_ignore_ascii_case(&b) => continue,
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Formats as `95Hz`, `190Hz`, `380Hz` or `760Hz`.
+impl Display for OutputDataRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}Hz", self.hz())
+    }
+}
+
+/// Parses values such as `380Hz`, `380 hz`, `380` or `Hz380`.
+impl FromStr for OutputDataRate {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match strip_prefix(strip_unit(s, "hz"), "hz") {
+            "95" => Ok(OutputDataRate::Hz95),
+            "190" => Ok(OutputDataRate::Hz190),
+            "380" => Ok(OutputDataRate::Hz380),
+            "760" => Ok(OutputDataRate::Hz760),
+            _ => Err(ParseEnumError::new("95Hz, 190Hz, 380Hz, 760Hz")),
+        }
+    }
+}
+
+/// Formats as `narrowest`, `narrow`, `medium` or `wide`.
+impl Display for Bandwidth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Bandwidth::Narrowest => "narrowest",
+            Bandwidth::Narrow => "narrow",
+            Bandwidth::Medium => "medium",
+            Bandwidth::Wide => "wide",
+        })
+    }
+}
+
+/// Parses the variant names, ignoring case.
+impl FromStr for Bandwidth {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if matches_name(s, "narrowest") {
+            Ok(Bandwidth::Narrowest)
+        } else if matches_name(s, "narrow") {
+            Ok(Bandwidth::Narrow)
+        } else if matches_name(s, "medium") {
+            Ok(Bandwidth::Medium)
+        } else if matches_name(s, "wide") {
+            Ok(Bandwidth::Wide)
+        } else {
+            Err(ParseEnumError::new("narrowest, narrow, medium, wide"))
+        }
+    }
+}
+
+/// Formats as `250dps`, `500dps` or `2000dps`.
+///
+/// Both [`Sensitivity::D2000`] and [`Sensitivity::D2000_11`] format as `2000dps`
+/// and parse back as [`Sensitivity::D2000`].
+impl Display for Sensitivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Sensitivity::D250 => "250dps",
+            Sensitivity::D500 => "500dps",
+            Sensitivity::D2000 | Sensitivity::D2000_11 => "2000dps",
+        })
+    }
+}
+
+/// Parses values such as `500dps`, `500 DPS`, `500` or `D500`.
+impl FromStr for Sensitivity {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match strip_prefix(strip_unit(s, "dps"), "d") {
+            "250" => Ok(Sensitivity::D250),
+            "500" => Ok(Sensitivity::D500),
+            "2000" => Ok(Sensitivity::D2000),
+            _ => Err(ParseEnumError::new("250dps, 500dps, 2000dps")),
+        }
+    }
+}
+
+/// Formats as `bypass`, `fifo`, `stream`, `stream-to-fifo` or `bypass-to-stream`.
+impl Display for FifoMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            FifoMode::Bypass => "bypass",
+            FifoMode::FIFO => "fifo",
+            FifoMode::Stream => "stream",
+            FifoMode::StreamToFifo => "stream-to-fifo",
+            FifoMode::BypassToStream => "bypass-to-stream",
+        })
+    }
+}
+
+/// Parses the mode names, ignoring case as well as `-` and `_` separators,
+/// e.g. `stream-to-fifo`, `stream_to_fifo` or `StreamToFifo`.
+impl FromStr for FifoMode {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if matches_name(s, "bypass") {
+            Ok(FifoMode::Bypass)
+        } else if matches_name(s, "fifo") {
+            Ok(FifoMode::FIFO)
+        } else if matches_name(s, "stream") {
+            Ok(FifoMode::Stream)
+        } else if matches_name(s, "stream-to-fifo") {
+            Ok(FifoMode::StreamToFifo)
+        } else if matches_name(s, "bypass-to-stream") {
+            Ok(FifoMode::BypassToStream)
+        } else {
+            Err(ParseEnumError::new(
+                "bypass, fifo, stream, stream-to-fifo, bypass-to-stream",
+            ))
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Composable processing of raw readings into physical units.
+
+use crate::{
+    AlignmentMatrix, AngularRate, AngularRateRaw, AngularUnit, CalibrationProfile, Sensitivity,
+};
+
+/// A sensor axis with a sign, the source of an output axis in an [`AxisRemap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SignedAxis {
+    /// The sensor X axis.
+    PosX,
+    /// The negated sensor X axis.
+    NegX,
+    /// The sensor Y axis.
+    PosY,
+    /// The negated sensor Y axis.
+    NegY,
+    /// The sensor Z axis.
+    PosZ,
+    /// The negated sensor Z axis.
+    NegZ,
+}
+
+impl SignedAxis {
+    /// Returns the index of the sensor axis, `0` to `2` for X to Z.
+    pub const fn index(self) -> usize {
+        match self {
+            SignedAxis::PosX | SignedAxis::NegX => 0,
+            SignedAxis::PosY | SignedAxis::NegY => 1,
+            SignedAxis::PosZ | SignedAxis::NegZ => 2,
+        }
+    }
+
+    /// Determines whether the axis is negated.
+    pub const fn is_negated(self) -> bool {
+        matches!(self, SignedAxis::NegX | SignedAxis::NegY | SignedAxis::NegZ)
+    }
+
+    const fn select_raw(self, values: [i16; 3]) -> i16 {
+        let value = values[self.index()];
+        if self.is_negated() {
+            value.saturating_neg()
+        } else {
+            value
+        }
+    }
+
+    fn select(self, values: [f32; 3]) -> f32 {
+        let value = values[self.index()];
+        if self.is_negated() {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+/// Maps the sensor axes to the axes of the application frame, e.g. to account for the
+/// mounting orientation of the sensor.
+///
+/// Each output axis takes its value from a sensor axis, optionally negated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AxisRemap {
+    /// The source of the output X axis.
+    pub x: SignedAxis,
+    /// The source of the output Y axis.
+    pub y: SignedAxis,
+    /// The source of the output Z axis.
+    pub z: SignedAxis,
+}
+
+impl AxisRemap {
+    /// The mapping that leaves the axes unchanged.
+    pub const IDENTITY: Self = Self::new(SignedAxis::PosX, SignedAxis::PosY, SignedAxis::PosZ);
+
+    /// Creates a mapping from the sources of the output X, Y and Z axes.
+    pub const fn new(x: SignedAxis, y: SignedAxis, z: SignedAxis) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Determines whether every sensor axis is used exactly once.
+    pub const fn is_permutation(&self) -> bool {
+        let (x, y, z) = (self.x.index(), self.y.index(), self.z.index());
+        x != y && y != z && x != z
+    }
+
+    /// Remaps a raw reading. Negating `i16::MIN` saturates.
+    #[must_use]
+    pub const fn apply_raw(&self, raw: AngularRateRaw) -> AngularRateRaw {
+        let values = [raw.x, raw.y, raw.z];
+        AngularRateRaw::new(
+            self.x.select_raw(values),
+            self.y.select_raw(values),
+            self.z.select_raw(values),
+        )
+    }
+
+    /// Remaps a scaled reading.
+    #[must_use]
+    pub fn apply(&self, rate: AngularRate) -> AngularRate {
+        let values = rate.to_dps();
+        AngularRate::from_dps(
+            self.x.select(values),
+            self.y.select(values),
+            self.z.select(values),
+        )
+    }
+}
+
+impl Default for AxisRemap {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Processes raw readings into physical units in a fixed order of stages:
+///
+/// 1. scaling according to the [`Sensitivity`],
+/// 2. bias removal in degrees per second,
+/// 3. alignment using an optional [`AlignmentMatrix`],
+/// 4. axis remapping using an [`AxisRemap`],
+/// 5. conversion into the output [`AngularUnit`].
+///
+/// Assemble the pipeline once and call [`process`](Self::process) for every reading.
+/// Stages that are not configured leave the reading unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Pipeline {
+    bias: [f32; 3],
+    sensitivity: Sensitivity,
+    alignment: Option<AlignmentMatrix>,
+    remap: AxisRemap,
+    unit: AngularUnit,
+}
+
+impl Pipeline {
+    /// Creates a pipeline that only scales readings into degrees per second.
+    pub const fn new(sensitivity: Sensitivity) -> Self {
+        Self {
+            bias: [0.0; 3],
+            sensitivity,
+            alignment: None,
+            remap: AxisRemap::IDENTITY,
+            unit: AngularUnit::DegreesPerSecond,
+        }
+    }
+
+    /// Creates a pipeline that applies a calibration profile.
+    pub fn from_profile(profile: &CalibrationProfile, sensitivity: Sensitivity) -> Self {
+        Self::new(sensitivity)
+            .with_bias(profile.bias())
+            .with_alignment(profile.alignment())
+    }
+
+    /// Sets the zero-rate bias of the X, Y and Z axes in degrees per second.
+    #[must_use]
+    pub const fn with_bias(mut self, bias: [f32; 3]) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    /// Sets the alignment applied after scaling.
+    #[must_use]
+    pub const fn with_alignment(mut self, alignment: Option<AlignmentMatrix>) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the axis mapping applied after alignment.
+    #[must_use]
+    pub const fn with_remap(mut self, remap: AxisRemap) -> Self {
+        self.remap = remap;
+        self
+    }
+
+    /// Sets the unit of the output.
+    #[must_use]
+    pub const fn with_unit(mut self, unit: AngularUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Changes the sensitivity, e.g. after switching the full scale.
+    pub fn set_sensitivity(&mut self, sensitivity: Sensitivity) {
+        self.sensitivity = sensitivity;
+    }
+
+    /// Returns the sensitivity.
+    pub const fn sensitivity(&self) -> Sensitivity {
+        self.sensitivity
+    }
+
+    /// Returns the output unit.
+    pub const fn unit(&self) -> AngularUnit {
+        self.unit
+    }
+
+    /// Processes a raw reading, returning the X, Y and Z values in the output unit.
+    #[must_use]
+    pub fn process(&self, raw: AngularRateRaw) -> [f32; 3] {
+        self.process_dps(raw).to_unit(self.unit)
+    }
+
+    /// Processes a raw reading up to the axis remapping, skipping the unit conversion.
+    #[must_use]
+    pub fn process_dps(&self, raw: AngularRateRaw) -> AngularRate {
+        let [x, y, z] = AngularRate::from_raw(raw, self.sensitivity).to_dps();
+        let mut rate = AngularRate::from_dps(x - self.bias[0], y - self.bias[1], z - self.bias[2]);
+        if let Some(alignment) = &self.alignment {
+            rate = alignment.apply_rate(rate);
+        }
+        self.remap.apply(rate)
+    }
+}
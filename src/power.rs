@@ -0,0 +1,439 @@
+//! Power modes and current consumption.
+
+use crate::{consts, Config, ControlRegister1, OutputDataRate, RegisterAddress};
+
+/// The operating mode selected in [`CTRL_REG1`](crate::RegisterAddress::CTRL_REG1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerMode {
+    /// Power-down mode (`PD = 0`).
+    PowerDown,
+    /// Sleep mode (`PD = 1`, all axes disabled). The sensor stays biased for a faster turn-on.
+    Sleep,
+    /// Normal mode (`PD = 1`, at least one axis enabled).
+    Normal,
+}
+
+impl PowerMode {
+    /// Returns the typical supply current in microamperes according to the datasheet.
+    ///
+    /// * 6.1 mA in [`PowerMode::Normal`]
+    /// * 1.5 mA in [`PowerMode::Sleep`]
+    /// * 5 µA in [`PowerMode::PowerDown`]
+    pub const fn typical_current_ua(self) -> u32 {
+        match self {
+            PowerMode::PowerDown => 5,
+            PowerMode::Sleep => 1_500,
+            PowerMode::Normal => 6_100,
+        }
+    }
+}
+
+/// A set of enabled axes, see [`ControlRegister1::with_axes`].
+///
+/// Disabling unused axes saves power; disabling all axes while powered up enters
+/// [`PowerMode::Sleep`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Axes(u8);
+
+impl Axes {
+    /// No axes.
+    pub const NONE: Self = Self(0b000);
+
+    /// The X axis.
+    pub const X: Self = Self(0b001);
+
+    /// The Y axis.
+    pub const Y: Self = Self(0b010);
+
+    /// The Z axis.
+    pub const Z: Self = Self(0b100);
+
+    /// All three axes.
+    pub const ALL: Self = Self(0b111);
+
+    /// Returns the bitmask of this set, with bit 0 being the X axis.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Combines two sets of axes.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Removes the axes in `other` from this set.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Determines whether all axes in `other` are part of this set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Determines whether the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Default for Axes {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl core::ops::BitOr for Axes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl ControlRegister1 {
+    /// Returns the power mode selected by this register.
+    pub const fn power_mode(&self) -> PowerMode {
+        if !self.power_up() {
+            PowerMode::PowerDown
+        } else if self.x_enable() || self.y_enable() || self.z_enable() {
+            PowerMode::Normal
+        } else {
+            PowerMode::Sleep
+        }
+    }
+
+    /// Returns the enabled axes.
+    pub const fn axes(&self) -> Axes {
+        let mut axes = Axes::NONE;
+        if self.x_enable() {
+            axes = axes.union(Axes::X);
+        }
+        if self.y_enable() {
+            axes = axes.union(Axes::Y);
+        }
+        if self.z_enable() {
+            axes = axes.union(Axes::Z);
+        }
+        axes
+    }
+
+    /// Enables exactly the specified axes, leaving all other bits unchanged.
+    #[must_use]
+    pub const fn with_axes(self, axes: Axes) -> Self {
+        self.with_x_enable(axes.contains(Axes::X))
+            .with_y_enable(axes.contains(Axes::Y))
+            .with_z_enable(axes.contains(Axes::Z))
+    }
+
+    /// Selects a power mode.
+    ///
+    /// Entering [`PowerMode::Sleep`] disables all axes, entering [`PowerMode::Normal`] enables
+    /// all axes if none are enabled. [`PowerMode::PowerDown`] leaves the axes unchanged.
+    #[must_use]
+    pub const fn with_power_mode(self, mode: PowerMode) -> Self {
+        match mode {
+            PowerMode::PowerDown => self.with_power_up(false),
+            PowerMode::Sleep => self
+                .with_power_up(true)
+                .with_x_enable(false)
+                .with_y_enable(false)
+                .with_z_enable(false),
+            PowerMode::Normal => {
+                let reg = self.with_power_up(true);
+                if reg.x_enable() || reg.y_enable() || reg.z_enable() {
+                    reg
+                } else {
+                    reg.with_x_enable(true)
+                        .with_y_enable(true)
+                        .with_z_enable(true)
+                }
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Returns the power mode of the configuration.
+    pub const fn power_mode(&self) -> PowerMode {
+        self.ctrl_reg1.power_mode()
+    }
+
+    /// Returns the register write that enters [`PowerMode::PowerDown`] while keeping the
+    /// output data rate, bandwidth and axes of this configuration.
+    ///
+    /// The configuration itself is left unchanged so that it can be restored using
+    /// [`resume_write`](Self::resume_write). All other registers retain their values
+    /// in power-down mode.
+    pub const fn suspend_write(&self) -> (RegisterAddress, u8) {
+        (
+            RegisterAddress::CTRL_REG1,
+            self.ctrl_reg1.with_power_up(false).into_bits(),
+        )
+    }
+
+    /// Returns the register write that restores the power mode of this configuration after
+    /// [`suspend_write`](Self::suspend_write), along with the transition to wait for before
+    /// readings are valid, if any.
+    pub const fn resume_write(&self) -> ((RegisterAddress, u8), Option<Transition>) {
+        (
+            (RegisterAddress::CTRL_REG1, self.ctrl_reg1.into_bits()),
+            Transition::between(PowerMode::PowerDown, self.power_mode()),
+        )
+    }
+
+    /// Estimates the typical supply current in microamperes.
+    ///
+    /// The datasheet only specifies the current per power mode; the output data rate,
+    /// bandwidth and number of enabled axes are not taken into account.
+    pub const fn estimated_current_ua(&self) -> u32 {
+        self.power_mode().typical_current_ua()
+    }
+}
+
+/// A change of the operating mode that requires waiting before readings are valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Transition {
+    /// Leaving [`PowerMode::PowerDown`], including the initial power-up.
+    PowerDownToNormal,
+    /// Leaving [`PowerMode::Sleep`].
+    SleepToNormal,
+    /// Rebooting the memory content using [`ControlRegister5::boot`](crate::ControlRegister5::boot).
+    Reboot,
+}
+
+impl Transition {
+    /// Determines the transition between two power modes, if it requires settling.
+    pub const fn between(from: PowerMode, to: PowerMode) -> Option<Self> {
+        match (from, to) {
+            (PowerMode::PowerDown, PowerMode::Normal) => Some(Transition::PowerDownToNormal),
+            (PowerMode::Sleep, PowerMode::Normal) => Some(Transition::SleepToNormal),
+            _ => None,
+        }
+    }
+
+    /// Returns the time to wait after the transition before readings are valid,
+    /// in microseconds.
+    ///
+    /// Sample-based settling times scale with the output data rate; see [`consts`] for the
+    /// underlying values, which are estimates.
+    pub const fn settling_time_us(self, odr: OutputDataRate) -> u32 {
+        let period_us = odr.period_us();
+        match self {
+            Transition::PowerDownToNormal => {
+                consts::TURN_ON_TIME_US + consts::TURN_ON_SAMPLES_FROM_POWER_DOWN * period_us
+            }
+            Transition::SleepToNormal => consts::TURN_ON_SAMPLES_FROM_SLEEP * period_us,
+            Transition::Reboot => consts::BOOT_TIME_US,
+        }
+    }
+
+    /// Waits for the transition to settle using the provided delay function,
+    /// e.g. `|us| delay.delay_us(us)`.
+    pub fn settle<D>(self, odr: OutputDataRate, mut delay_us: D)
+    where
+        D: FnMut(u32),
+    {
+        delay_us(self.settling_time_us(odr));
+    }
+}
+
+/// Sequences duty-cycled acquisition: power up, discard the settling samples,
+/// record a number of readings and power down again.
+///
+/// The cycler does not access the sensor. Write the register returned by
+/// [`start`](Self::start), then call [`observe`](Self::observe) for every new sample and
+/// keep the samples it asks to record. Once no samples remain, power down using
+/// [`Config::suspend_write`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DutyCycler {
+    discard_samples: u32,
+    record_samples: u32,
+    discarded: u32,
+    remaining: u32,
+}
+
+/// The action to take for a sample, see [`DutyCycler::observe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DutyCycleStep {
+    /// The sample was taken while the sensor was settling and should be discarded.
+    Discard,
+    /// The sample should be recorded. The sensor can be powered down if no samples remain.
+    Record {
+        /// The number of samples still to be recorded.
+        remaining: u32,
+    },
+    /// No acquisition is in progress.
+    Idle,
+}
+
+impl DutyCycler {
+    /// Creates a cycler recording the specified number of samples per cycle, discarding
+    /// [`TURN_ON_SAMPLES_FROM_POWER_DOWN`](consts::TURN_ON_SAMPLES_FROM_POWER_DOWN) samples first.
+    pub const fn new(record_samples: u32) -> Self {
+        Self {
+            discard_samples: consts::TURN_ON_SAMPLES_FROM_POWER_DOWN,
+            record_samples,
+            discarded: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Sets the number of settling samples to discard after power-up.
+    #[must_use]
+    pub const fn with_discard_samples(mut self, samples: u32) -> Self {
+        self.discard_samples = samples;
+        self
+    }
+
+    /// Starts a cycle and returns the register write that powers up the sensor
+    /// according to the configuration.
+    pub fn start(&mut self, config: &Config) -> (RegisterAddress, u8) {
+        self.discarded = 0;
+        self.remaining = self.record_samples;
+        (
+            RegisterAddress::CTRL_REG1,
+            config
+                .ctrl_reg1
+                .with_power_mode(PowerMode::Normal)
+                .into_bits(),
+        )
+    }
+
+    /// Determines the action for a new sample.
+    pub fn observe(&mut self) -> DutyCycleStep {
+        if self.remaining == 0 {
+            return DutyCycleStep::Idle;
+        }
+        if self.discarded < self.discard_samples {
+            self.discarded += 1;
+            return DutyCycleStep::Discard;
+        }
+        self.remaining -= 1;
+        DutyCycleStep::Record {
+            remaining: self.remaining,
+        }
+    }
+
+    /// Determines whether a cycle is in progress.
+    pub const fn is_active(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Aborts the current cycle.
+    pub fn cancel(&mut self) {
+        self.remaining = 0;
+    }
+}
+
+/// The number of samples to discard after mode transitions, as the first readings are not
+/// representative while the sensor settles.
+///
+/// The [`Default`] policy uses the values from [`consts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DiscardPolicy {
+    /// Samples to discard after leaving power-down mode or rebooting.
+    pub power_up: u32,
+    /// Samples to discard after leaving sleep mode.
+    pub wake: u32,
+    /// Samples to discard after changing the full scale, output data rate or bandwidth.
+    pub reconfiguration: u32,
+}
+
+impl DiscardPolicy {
+    /// A conservative policy using the estimates [`consts::TURN_ON_SAMPLES_FROM_POWER_DOWN`],
+    /// [`consts::TURN_ON_SAMPLES_FROM_SLEEP`] and
+    /// [`consts::SETTLING_SAMPLES_AFTER_RECONFIGURATION`].
+    ///
+    /// The datasheet does not specify settling behavior, so these values are not
+    /// manufacturer recommendations.
+    pub const RECOMMENDED: Self = Self {
+        power_up: consts::TURN_ON_SAMPLES_FROM_POWER_DOWN,
+        wake: consts::TURN_ON_SAMPLES_FROM_SLEEP,
+        reconfiguration: consts::SETTLING_SAMPLES_AFTER_RECONFIGURATION,
+    };
+
+    /// A policy that keeps all samples.
+    pub const NONE: Self = Self {
+        power_up: 0,
+        wake: 0,
+        reconfiguration: 0,
+    };
+
+    /// Returns the number of samples to discard after a transition.
+    pub const fn samples_after(&self, transition: Transition) -> u32 {
+        match transition {
+            Transition::PowerDownToNormal | Transition::Reboot => self.power_up,
+            Transition::SleepToNormal => self.wake,
+        }
+    }
+}
+
+impl Default for DiscardPolicy {
+    fn default() -> Self {
+        Self::RECOMMENDED
+    }
+}
+
+/// Discards the samples following mode transitions according to a [`DiscardPolicy`].
+///
+/// Report transitions using [`on_transition`](Self::on_transition) and
+/// [`on_reconfiguration`](Self::on_reconfiguration), then call [`accept`](Self::accept)
+/// for every new sample.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SampleDiscarder {
+    policy: DiscardPolicy,
+    remaining: u32,
+}
+
+impl SampleDiscarder {
+    /// Creates a discarder that keeps samples until a transition is reported.
+    pub const fn new(policy: DiscardPolicy) -> Self {
+        Self {
+            policy,
+            remaining: 0,
+        }
+    }
+
+    /// Returns the policy.
+    pub const fn policy(&self) -> DiscardPolicy {
+        self.policy
+    }
+
+    /// Starts discarding after a power mode transition or reboot.
+    pub fn on_transition(&mut self, transition: Transition) {
+        self.discard(self.policy.samples_after(transition));
+    }
+
+    /// Starts discarding after changing the full scale, output data rate or bandwidth.
+    pub fn on_reconfiguration(&mut self) {
+        self.discard(self.policy.reconfiguration);
+    }
+
+    /// Determines whether a new sample should be kept, counting it against the samples
+    /// to discard.
+    pub fn accept(&mut self) -> bool {
+        if self.remaining == 0 {
+            true
+        } else {
+            self.remaining -= 1;
+            false
+        }
+    }
+
+    /// Returns the number of samples still to be discarded.
+    pub const fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    fn discard(&mut self, samples: u32) {
+        self.remaining = self.remaining.max(samples);
+    }
+}
@@ -0,0 +1,178 @@
+//! Recording of bus traffic for post-mortem analysis.
+
+use crate::{BusTransaction, Direction, RegisterAddress, RegisterBus};
+
+/// A transfer recorded by a [`Recorder`], keeping up to `LEN` data bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RecordedTransaction<const LEN: usize> {
+    direction: Direction,
+    start: RegisterAddress,
+    len: usize,
+    data: [u8; LEN],
+}
+
+impl<const LEN: usize> RecordedTransaction<LEN> {
+    const EMPTY: Self = Self {
+        direction: Direction::Read,
+        start: RegisterAddress::WHO_AM_I,
+        len: 0,
+        data: [0; LEN],
+    };
+
+    fn new(direction: Direction, start: RegisterAddress, bytes: &[u8]) -> Self {
+        let mut data = [0; LEN];
+        let stored = bytes.len().min(LEN);
+        data[..stored].copy_from_slice(&bytes[..stored]);
+        Self {
+            direction,
+            start,
+            len: bytes.len(),
+            data,
+        }
+    }
+
+    /// Returns the direction of the transfer.
+    pub const fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the first register of the transfer.
+    pub const fn start(&self) -> RegisterAddress {
+        self.start
+    }
+
+    /// Returns the length of the transfer in bytes, including bytes that were not kept.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determines whether the transfer was empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Determines whether the transfer was longer than `LEN` bytes, so that only its first
+    /// bytes were kept.
+    pub const fn is_truncated(&self) -> bool {
+        self.len > LEN
+    }
+
+    /// Returns the kept data bytes, in register order.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len.min(LEN)]
+    }
+
+    /// Returns the transfer as a [`BusTransaction`], e.g. for playback using
+    /// [`Replay`](crate::Replay).
+    ///
+    /// Truncated transfers only contain the kept bytes and will not match during playback.
+    pub fn as_transaction(&self) -> BusTransaction<'_> {
+        BusTransaction {
+            direction: self.direction,
+            start: self.start,
+            data: self.data(),
+        }
+    }
+}
+
+/// A [`RegisterBus`] wrapper recording the last `N` successful transfers in a ring buffer,
+/// keeping up to `LEN` data bytes of each.
+///
+/// Failed transfers are not recorded. Once the buffer is full, the oldest transfers are
+/// overwritten; see [`overwritten`](Self::overwritten).
+#[derive(Debug, Clone)]
+pub struct Recorder<B, const N: usize, const LEN: usize = 8> {
+    bus: B,
+    log: [RecordedTransaction<LEN>; N],
+    next: usize,
+    len: usize,
+    overwritten: u32,
+}
+
+impl<B, const N: usize, const LEN: usize> Recorder<B, N, LEN> {
+    const VALID_SIZE: () = assert!(N > 0, "the recorder must hold at least one transfer");
+
+    /// Wraps a bus.
+    pub const fn new(bus: B) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SIZE;
+        Self {
+            bus,
+            log: [RecordedTransaction::EMPTY; N],
+            next: 0,
+            len: 0,
+            overwritten: 0,
+        }
+    }
+
+    /// Returns the number of recorded transfers.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determines whether no transfer was recorded.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of transfers that were overwritten by newer ones.
+    pub const fn overwritten(&self) -> u32 {
+        self.overwritten
+    }
+
+    /// Iterates the recorded transfers from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &RecordedTransaction<LEN>> + '_ {
+        let start = (self.next + N - self.len) % N;
+        (0..self.len).map(move |offset| &self.log[(start + offset) % N])
+    }
+
+    /// Removes all recorded transfers.
+    pub fn clear(&mut self) {
+        self.next = 0;
+        self.len = 0;
+        self.overwritten = 0;
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+
+    fn record(&mut self, direction: Direction, start: RegisterAddress, bytes: &[u8]) {
+        self.log[self.next] = RecordedTransaction::new(direction, start, bytes);
+        self.next = (self.next + 1) % N;
+        if self.len == N {
+            self.overwritten = self.overwritten.saturating_add(1);
+        } else {
+            self.len += 1;
+        }
+    }
+}
+
+impl<B, const N: usize, const LEN: usize> RegisterBus for Recorder<B, N, LEN>
+where
+    B: RegisterBus,
+{
+    type Error = B::Error;
+
+    fn read_registers(
+        &mut self,
+        start: RegisterAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.read_registers(start, buffer)?;
+        self.record(Direction::Read, start, buffer);
+        Ok(())
+    }
+
+    fn write_registers(
+        &mut self,
+        start: RegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.bus.write_registers(start, values)?;
+        self.record(Direction::Write, start, values);
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+//! Field descriptors for generic register access, e.g. in debug shells or GUIs.
+
+use crate::{
+    ControlRegister1, ControlRegister2, ControlRegister3, ControlRegister4, ControlRegister5,
+    FifoControlRegister, FifoSourceRegister, Int1ConfigurationRegister, Int1DurationRegister,
+    Int1SourceRegisterA, Int1ThresholdRegisterXH, Int1ThresholdRegisterXL, Int1ThresholdRegisterYH,
+    Int1ThresholdRegisterYL, Int1ThresholdRegisterZH, Int1ThresholdRegisterZL, OutXHigh, OutXLow,
+    OutYHigh, OutYLow, OutZHigh, OutZLow, ReferenceRegister, RegisterAddress, StatusRegister,
+    TemperatureRegister, WhoAmI,
+};
+use core::ops::Range;
+use core::slice::Iter;
+
+/// How a register field can be accessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FieldAccess {
+    /// The field can only be read.
+    ReadOnly,
+    /// The field can be read and written.
+    ReadWrite,
+}
+
+/// Describes a field of a register, see [`Register::FIELDS`](crate::Register::FIELDS).
+///
+/// Reserved bits are not described.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FieldDescriptor {
+    name: &'static str,
+    offset: u8,
+    bits: u8,
+    access: FieldAccess,
+}
+
+impl FieldDescriptor {
+    /// Creates a descriptor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is empty or does not fit into a byte, i.e. unless
+    /// `1 <= bits` and `offset + bits <= 8`.
+    pub const fn new(name: &'static str, offset: u8, bits: u8, access: FieldAccess) -> Self {
+        assert!(
+            bits >= 1 && bits <= 8 && offset <= 8 - bits,
+            "the field must cover 1 to 8 bits within the register"
+        );
+        Self {
+            name,
+            offset,
+            bits,
+            access,
+        }
+    }
+
+    /// Returns the name of the field, matching the accessor of the register type.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the position of the least significant bit.
+    pub const fn offset(&self) -> u8 {
+        self.offset
+    }
+
+    /// Returns the width in bits.
+    pub const fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Returns how the field can be accessed.
+    pub const fn access(&self) -> FieldAccess {
+        self.access
+    }
+
+    /// Returns the bit positions covered by the field.
+    pub const fn bit_range(&self) -> Range<u8> {
+        self.offset..self.offset + self.bits
+    }
+
+    /// Returns the mask of the field within the register.
+    pub const fn mask(&self) -> u8 {
+        (u8::MAX >> (8 - self.bits)) << self.offset
+    }
+
+    /// Determines whether the field can be written.
+    pub const fn is_writable(&self) -> bool {
+        matches!(self.access, FieldAccess::ReadWrite)
+    }
+
+    /// Extracts the field value from a register value.
+    pub const fn get(&self, register: u8) -> u8 {
+        (register & self.mask()) >> self.offset
+    }
+
+    /// Replaces the field value in a register value. Excess bits of `value` are ignored.
+    pub const fn set(&self, register: u8, value: u8) -> u8 {
+        (register & !self.mask()) | ((value << self.offset) & self.mask())
+    }
+}
+
+/// An iterator over the fields overlapping a bit mask, e.g. the fields deviating from the
+/// reset value.
+///
+/// See [`Register::deviating_fields`](crate::Register::deviating_fields) and
+/// [`ResetDeviation::fields`](crate::ResetDeviation::fields).
+#[derive(Debug, Clone)]
+pub struct DeviatingFields {
+    fields: Iter<'static, FieldDescriptor>,
+    mask: u8,
+}
+
+impl DeviatingFields {
+    pub(crate) fn new(fields: &'static [FieldDescriptor], mask: u8) -> Self {
+        Self {
+            fields: fields.iter(),
+            mask,
+        }
+    }
+}
+
+impl Iterator for DeviatingFields {
+    type Item = &'static FieldDescriptor;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask = self.mask;
+        self.fields.find(|field| field.mask() & mask != 0)
+    }
+}
+
+impl RegisterAddress {
+    /// Returns the field descriptors of the register, from the most significant bit.
+    pub const fn fields(&self) -> &'static [FieldDescriptor] {
+        match self {
+            RegisterAddress::WHO_AM_I => &WhoAmI::FIELDS,
+            RegisterAddress::CTRL_REG1 => &ControlRegister1::FIELDS,
+            RegisterAddress::CTRL_REG2 => &ControlRegister2::FIELDS,
+            RegisterAddress::CTRL_REG3 => &ControlRegister3::FIELDS,
+            RegisterAddress::CTRL_REG4 => &ControlRegister4::FIELDS,
+            RegisterAddress::CTRL_REG5 => &ControlRegister5::FIELDS,
+            RegisterAddress::REFERENCE => &ReferenceRegister::FIELDS,
+            RegisterAddress::OUT_TEMP => &TemperatureRegister::FIELDS,
+            RegisterAddress::STATUS_REG => &StatusRegister::FIELDS,
+            RegisterAddress::OUT_X_L => &OutXLow::FIELDS,
+            RegisterAddress::OUT_X_H => &OutXHigh::FIELDS,
+            RegisterAddress::OUT_Y_L => &OutYLow::FIELDS,
+            RegisterAddress::OUT_Y_H => &OutYHigh::FIELDS,
+            RegisterAddress::OUT_Z_L => &OutZLow::FIELDS,
+            RegisterAddress::OUT_Z_H => &OutZHigh::FIELDS,
+            RegisterAddress::FIFO_CTRL_REG => &FifoControlRegister::FIELDS,
+            RegisterAddress::FIFO_SRC_REG => &FifoSourceRegister::FIELDS,
+            RegisterAddress::INT1_CFG => &Int1ConfigurationRegister::FIELDS,
+            RegisterAddress::INT1_SRC => &Int1SourceRegisterA::FIELDS,
+            RegisterAddress::INT1_TSH_XH => &Int1ThresholdRegisterXH::FIELDS,
+            RegisterAddress::INT1_TSH_XL => &Int1ThresholdRegisterXL::FIELDS,
+            RegisterAddress::INT1_TSH_YH => &Int1ThresholdRegisterYH::FIELDS,
+            RegisterAddress::INT1_TSH_YL => &Int1ThresholdRegisterYL::FIELDS,
+            RegisterAddress::INT1_TSH_ZH => &Int1ThresholdRegisterZH::FIELDS,
+            RegisterAddress::INT1_TSH_ZL => &Int1ThresholdRegisterZL::FIELDS,
+            RegisterAddress::INT1_DURATION => &Int1DurationRegister::FIELDS,
+        }
+    }
+
+    /// Looks up a field of the register by name.
+    pub fn field(&self, name: &str) -> Option<&'static FieldDescriptor> {
+        self.fields().iter().find(|field| field.name() == name)
+    }
+}
@@ -0,0 +1,834 @@
+//! Snapshots of the complete register map.
+
+use crate::{
+    ControlRegister1, ControlRegister2, ControlRegister3, ControlRegister4, ControlRegister5,
+    DeviatingFields, FifoControlRegister, FifoSourceRegister, Int1ConfigurationRegister,
+    Int1DurationRegister, Int1SourceRegisterA, Int1ThresholdRegisterXH, Int1ThresholdRegisterXL,
+    Int1ThresholdRegisterYH, Int1ThresholdRegisterYL, Int1ThresholdRegisterZH,
+    Int1ThresholdRegisterZL, OutXHigh, OutXLow, OutYHigh, OutYLow, OutZHigh, OutZLow,
+    ReferenceRegister, RegisterAddress, StatusRegister, TemperatureRegister, WhoAmI,
+};
+
+/// A snapshot of all registers, e.g. decoded from a captured register dump.
+///
+/// The registers `CTRL_REG1` (20h) through `INT1_DURATION` (38h) form a contiguous block
+/// that can be read in a single auto-increment burst. [`WHO_AM_I`](RegisterAddress::WHO_AM_I) (0Fh)
+/// is located outside of that block and only available if the dump covers it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub struct RegisterFile {
+    pub who_am_i: Option<WhoAmI>,
+    pub ctrl_reg1: ControlRegister1,
+    pub ctrl_reg2: ControlRegister2,
+    pub ctrl_reg3: ControlRegister3,
+    pub ctrl_reg4: ControlRegister4,
+    pub ctrl_reg5: ControlRegister5,
+    pub reference: ReferenceRegister,
+    pub out_temp: TemperatureRegister,
+    pub status: StatusRegister,
+    pub out_x_l: OutXLow,
+    pub out_x_h: OutXHigh,
+    pub out_y_l: OutYLow,
+    pub out_y_h: OutYHigh,
+    pub out_z_l: OutZLow,
+    pub out_z_h: OutZHigh,
+    pub fifo_ctrl: FifoControlRegister,
+    pub fifo_src: FifoSourceRegister,
+    pub int1_cfg: Int1ConfigurationRegister,
+    pub int1_src: Int1SourceRegisterA,
+    pub int1_tsh_xh: Int1ThresholdRegisterXH,
+    pub int1_tsh_xl: Int1ThresholdRegisterXL,
+    pub int1_tsh_yh: Int1ThresholdRegisterYH,
+    pub int1_tsh_yl: Int1ThresholdRegisterYL,
+    pub int1_tsh_zh: Int1ThresholdRegisterZH,
+    pub int1_tsh_zl: Int1ThresholdRegisterZL,
+    pub int1_duration: Int1DurationRegister,
+}
+
+impl RegisterFile {
+    /// The first register of the contiguous block, [`CTRL_REG1`](RegisterAddress::CTRL_REG1).
+    pub const BLOCK_START: RegisterAddress = RegisterAddress::CTRL_REG1;
+
+    /// The last register of the contiguous block, [`INT1_DURATION`](RegisterAddress::INT1_DURATION).
+    pub const BLOCK_END: RegisterAddress = RegisterAddress::INT1_DURATION;
+
+    /// The number of bytes in the contiguous block, `CTRL_REG1` (20h) through `INT1_DURATION` (38h).
+    pub const BLOCK_LEN: usize = (Self::BLOCK_END.addr() - Self::BLOCK_START.addr()) as usize + 1;
+
+    /// Decodes the contiguous block `CTRL_REG1` (20h) through `INT1_DURATION` (38h).
+    ///
+    /// The [`who_am_i`](RegisterFile::who_am_i) register is not part of the block and is set to `None`.
+    ///
+    /// Every byte value is accepted. Reserved field values decode as described by the field
+    /// accessors, e.g. [`FifoControlRegister::fifo_mode`].
+    pub fn from_block(block: &[u8; Self::BLOCK_LEN]) -> Self {
+        Self {
+            who_am_i: None,
+            ctrl_reg1: ControlRegister1::from_bits(block[0x00]),
+            ctrl_reg2: ControlRegister2::from_bits(block[0x01]),
+            ctrl_reg3: ControlRegister3::from_bits(block[0x02]),
+            ctrl_reg4: ControlRegister4::from_bits(block[0x03]),
+            ctrl_reg5: ControlRegister5::from_bits(block[0x04]),
+            reference: ReferenceRegister::from_bits(block[0x05]),
+            out_temp: TemperatureRegister::from_bits(block[0x06]),
+            status: StatusRegister::from_bits(block[0x07]),
+            out_x_l: OutXLow::from_bits(block[0x08]),
+            out_x_h: OutXHigh::from_bits(block[0x09]),
+            out_y_l: OutYLow::from_bits(block[0x0A]),
+            out_y_h: OutYHigh::from_bits(block[0x0B]),
+            out_z_l: OutZLow::from_bits(block[0x0C]),
+            out_z_h: OutZHigh::from_bits(block[0x0D]),
+            fifo_ctrl: FifoControlRegister::from_bits(block[0x0E]),
+            fifo_src: FifoSourceRegister::from_bits(block[0x0F]),
+            int1_cfg: Int1ConfigurationRegister::from_bits(block[0x10]),
+            int1_src: Int1SourceRegisterA::from_bits(block[0x11]),
+            int1_tsh_xh: Int1ThresholdRegisterXH::from_bits(block[0x12]),
+            int1_tsh_xl: Int1ThresholdRegisterXL::from_bits(block[0x13]),
+            int1_tsh_yh: Int1ThresholdRegisterYH::from_bits(block[0x14]),
+            int1_tsh_yl: Int1ThresholdRegisterYL::from_bits(block[0x15]),
+            int1_tsh_zh: Int1ThresholdRegisterZH::from_bits(block[0x16]),
+            int1_tsh_zl: Int1ThresholdRegisterZL::from_bits(block[0x17]),
+            int1_duration: Int1DurationRegister::from_bits(block[0x18]),
+        }
+    }
+
+    /// Decodes a register dump whose first byte is located at `start_address`.
+    ///
+    /// The dump must cover the contiguous block `CTRL_REG1` (20h) through `INT1_DURATION` (38h);
+    /// additional bytes are ignored. If the dump starts at or before `WHO_AM_I` (0Fh), that
+    /// register is decoded as well.
+    pub fn from_dump(start_address: u8, dump: &[u8]) -> Result<Self, DumpError> {
+        if start_address > Self::BLOCK_START.addr() {
+            return Err(DumpError::InvalidStartAddress(start_address));
+        }
+
+        let offset = (Self::BLOCK_START.addr() - start_address) as usize;
+        let required = offset + Self::BLOCK_LEN;
+        if dump.len() < required {
+            return Err(DumpError::InvalidLength {
+                expected: required,
+                actual: dump.len(),
+            });
+        }
+
+        let mut block = [0; Self::BLOCK_LEN];
+        block.copy_from_slice(&dump[offset..required]);
+        let mut file = Self::from_block(&block);
+
+        let who_am_i = RegisterAddress::WHO_AM_I.addr();
+        if start_address <= who_am_i {
+            file.who_am_i = Some(WhoAmI::from_bits(dump[(who_am_i - start_address) as usize]));
+        }
+
+        Ok(file)
+    }
+
+    /// Encodes the contiguous block `CTRL_REG1` (20h) through `INT1_DURATION` (38h).
+    pub fn to_block(&self) -> [u8; Self::BLOCK_LEN] {
+        let mut block = [0; Self::BLOCK_LEN];
+        for (address, byte) in (Self::BLOCK_START.addr()..).zip(block.iter_mut()) {
+            *byte = self.byte_at_raw(address).unwrap_or_default();
+        }
+        block
+    }
+
+    /// Returns the byte value of the register at the specified address.
+    ///
+    /// Returns `None` for [`WHO_AM_I`](RegisterAddress::WHO_AM_I) if it was not captured.
+    pub fn byte_at(&self, address: RegisterAddress) -> Option<u8> {
+        self.byte_at_raw(address.addr())
+    }
+
+    /// Returns the decoded value of the register at the specified address.
+    ///
+    /// Returns `None` for [`WHO_AM_I`](RegisterAddress::WHO_AM_I) if it was not captured.
+    pub fn value_at(&self, address: RegisterAddress) -> Option<RegisterValue> {
+        self.byte_at(address)
+            .map(|byte| RegisterValue::decode(address, byte))
+    }
+
+    /// Iterates all captured registers in address order.
+    pub fn iter(&self) -> RegisterFileIter {
+        RegisterFileIter {
+            file: *self,
+            index: 0,
+        }
+    }
+
+    /// Iterates all registers whose value deviates from their documented reset value.
+    ///
+    /// Registers without a documented reset value and an uncaptured
+    /// [`who_am_i`](RegisterFile::who_am_i) are skipped.
+    pub fn reset_deviations(&self) -> impl Iterator<Item = ResetDeviation> + '_ {
+        RegisterAddress::ALL.into_iter().filter_map(move |address| {
+            let expected = address.reset_value()?;
+            let actual = self.byte_at(address)?;
+            if expected == actual {
+                None
+            } else {
+                Some(ResetDeviation {
+                    address,
+                    expected,
+                    actual,
+                })
+            }
+        })
+    }
+
+    /// Determines whether all registers hold their documented reset values.
+    pub fn is_default(&self) -> bool {
+        self.reset_deviations().next().is_none()
+    }
+
+    /// Iterates all registers whose value differs from the same register in another snapshot,
+    /// e.g. to find what changed between two dumps.
+    ///
+    /// [`WHO_AM_I`](RegisterAddress::WHO_AM_I) is skipped unless captured in both snapshots.
+    pub fn changes<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = RegisterChange> + 'a {
+        RegisterAddress::ALL.into_iter().filter_map(move |address| {
+            let before = self.byte_at(address)?;
+            let after = other.byte_at(address)?;
+            if before == after {
+                None
+            } else {
+                Some(RegisterChange {
+                    address,
+                    before,
+                    after,
+                })
+            }
+        })
+    }
+
+    /// Returns a [`Display`](core::fmt::Display) adapter rendering the snapshot as an
+    /// aligned table of addresses, names, values and decoded fields.
+    pub const fn display(&self) -> RegisterDumpDisplay<'_> {
+        RegisterDumpDisplay { file: self }
+    }
+
+    /// Returns all captured registers in address order.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_vec(&self) -> alloc::vec::Vec<(RegisterAddress, RegisterValue)> {
+        self.iter().collect()
+    }
+
+    /// Returns all registers whose value deviates from their documented reset value.
+    ///
+    /// See [`reset_deviations`](Self::reset_deviations).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn reset_deviations_vec(&self) -> alloc::vec::Vec<ResetDeviation> {
+        self.reset_deviations().collect()
+    }
+
+    /// Returns all registers whose value differs from the same register in another snapshot.
+    ///
+    /// See [`changes`](Self::changes).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn changes_vec(&self, other: &Self) -> alloc::vec::Vec<RegisterChange> {
+        self.changes(other).collect()
+    }
+
+    fn byte_at_raw(&self, address: u8) -> Option<u8> {
+        Some(match address {
+            0x0F => return self.who_am_i.map(WhoAmI::into_bits),
+            0x20 => self.ctrl_reg1.into_bits(),
+            0x21 => self.ctrl_reg2.into_bits(),
+            0x22 => self.ctrl_reg3.into_bits(),
+            0x23 => self.ctrl_reg4.into_bits(),
+            0x24 => self.ctrl_reg5.into_bits(),
+            0x25 => self.reference.into_bits(),
+            0x26 => self.out_temp.into_bits(),
+            0x27 => self.status.into_bits(),
+            0x28 => self.out_x_l.into_bits(),
+            0x29 => self.out_x_h.into_bits(),
+            0x2A => self.out_y_l.into_bits(),
+            0x2B => self.out_y_h.into_bits(),
+            0x2C => self.out_z_l.into_bits(),
+            0x2D => self.out_z_h.into_bits(),
+            0x2E => self.fifo_ctrl.into_bits(),
+            0x2F => self.fifo_src.into_bits(),
+            0x30 => self.int1_cfg.into_bits(),
+            0x31 => self.int1_src.into_bits(),
+            0x32 => self.int1_tsh_xh.into_bits(),
+            0x33 => self.int1_tsh_xl.into_bits(),
+            0x34 => self.int1_tsh_yh.into_bits(),
+            0x35 => self.int1_tsh_yl.into_bits(),
+            0x36 => self.int1_tsh_zh.into_bits(),
+            0x37 => self.int1_tsh_zl.into_bits(),
+            0x38 => self.int1_duration.into_bits(),
+            _ => return None,
+        })
+    }
+}
+
+impl IntoIterator for RegisterFile {
+    type Item = (RegisterAddress, RegisterValue);
+    type IntoIter = RegisterFileIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &RegisterFile {
+    type Item = (RegisterAddress, RegisterValue);
+    type IntoIter = RegisterFileIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the registers of a [`RegisterFile`] in address order.
+#[derive(Debug, Clone)]
+pub struct RegisterFileIter {
+    file: RegisterFile,
+    index: usize,
+}
+
+impl Iterator for RegisterFileIter {
+    type Item = (RegisterAddress, RegisterValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(address) = RegisterAddress::ALL.get(self.index) {
+            self.index += 1;
+            if let Some(value) = self.file.value_at(*address) {
+                return Some((*address, value));
+            }
+        }
+        None
+    }
+}
+
+/// The decoded value of any register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum RegisterValue {
+    WhoAmI(WhoAmI),
+    ControlRegister1(ControlRegister1),
+    ControlRegister2(ControlRegister2),
+    ControlRegister3(ControlRegister3),
+    ControlRegister4(ControlRegister4),
+    ControlRegister5(ControlRegister5),
+    ReferenceRegister(ReferenceRegister),
+    TemperatureRegister(TemperatureRegister),
+    StatusRegister(StatusRegister),
+    OutXLow(OutXLow),
+    OutXHigh(OutXHigh),
+    OutYLow(OutYLow),
+    OutYHigh(OutYHigh),
+    OutZLow(OutZLow),
+    OutZHigh(OutZHigh),
+    FifoControlRegister(FifoControlRegister),
+    FifoSourceRegister(FifoSourceRegister),
+    Int1ConfigurationRegister(Int1ConfigurationRegister),
+    Int1SourceRegisterA(Int1SourceRegisterA),
+    Int1ThresholdRegisterXH(Int1ThresholdRegisterXH),
+    Int1ThresholdRegisterXL(Int1ThresholdRegisterXL),
+    Int1ThresholdRegisterYH(Int1ThresholdRegisterYH),
+    Int1ThresholdRegisterYL(Int1ThresholdRegisterYL),
+    Int1ThresholdRegisterZH(Int1ThresholdRegisterZH),
+    Int1ThresholdRegisterZL(Int1ThresholdRegisterZL),
+    Int1DurationRegister(Int1DurationRegister),
+}
+
+impl RegisterValue {
+    /// Decodes the byte value of the register at the specified address.
+    pub const fn decode(address: RegisterAddress, value: u8) -> Self {
+        match address {
+            RegisterAddress::WHO_AM_I => Self::WhoAmI(WhoAmI::from_bits(value)),
+            RegisterAddress::CTRL_REG1 => {
+                Self::ControlRegister1(ControlRegister1::from_bits(value))
+            }
+            RegisterAddress::CTRL_REG2 => {
+                Self::ControlRegister2(ControlRegister2::from_bits(value))
+            }
+            RegisterAddress::CTRL_REG3 => {
+                Self::ControlRegister3(ControlRegister3::from_bits(value))
+            }
+            RegisterAddress::CTRL_REG4 => {
+                Self::ControlRegister4(ControlRegister4::from_bits(value))
+            }
+            RegisterAddress::CTRL_REG5 => {
+                Self::ControlRegister5(ControlRegister5::from_bits(value))
+            }
+            RegisterAddress::REFERENCE => {
+                Self::ReferenceRegister(ReferenceRegister::from_bits(value))
+            }
+            RegisterAddress::OUT_TEMP => {
+                Self::TemperatureRegister(TemperatureRegister::from_bits(value))
+            }
+            RegisterAddress::STATUS_REG => Self::StatusRegister(StatusRegister::from_bits(value)),
+            RegisterAddress::OUT_X_L => Self::OutXLow(OutXLow::from_bits(value)),
+            RegisterAddress::OUT_X_H => Self::OutXHigh(OutXHigh::from_bits(value)),
+            RegisterAddress::OUT_Y_L => Self::OutYLow(OutYLow::from_bits(value)),
+            RegisterAddress::OUT_Y_H => Self::OutYHigh(OutYHigh::from_bits(value)),
+            RegisterAddress::OUT_Z_L => Self::OutZLow(OutZLow::from_bits(value)),
+            RegisterAddress::OUT_Z_H => Self::OutZHigh(OutZHigh::from_bits(value)),
+            RegisterAddress::FIFO_CTRL_REG => {
+                Self::FifoControlRegister(FifoControlRegister::from_bits(value))
+            }
+            RegisterAddress::FIFO_SRC_REG => {
+                Self::FifoSourceRegister(FifoSourceRegister::from_bits(value))
+            }
+            RegisterAddress::INT1_CFG => {
+                Self::Int1ConfigurationRegister(Int1ConfigurationRegister::from_bits(value))
+            }
+            RegisterAddress::INT1_SRC => {
+                Self::Int1SourceRegisterA(Int1SourceRegisterA::from_bits(value))
+            }
+            RegisterAddress::INT1_TSH_XH => {
+                Self::Int1ThresholdRegisterXH(Int1ThresholdRegisterXH::from_bits(value))
+            }
+            RegisterAddress::INT1_TSH_XL => {
+                Self::Int1ThresholdRegisterXL(Int1ThresholdRegisterXL::from_bits(value))
+            }
+            RegisterAddress::INT1_TSH_YH => {
+                Self::Int1ThresholdRegisterYH(Int1ThresholdRegisterYH::from_bits(value))
+            }
+            RegisterAddress::INT1_TSH_YL => {
+                Self::Int1ThresholdRegisterYL(Int1ThresholdRegisterYL::from_bits(value))
+            }
+            RegisterAddress::INT1_TSH_ZH => {
+                Self::Int1ThresholdRegisterZH(Int1ThresholdRegisterZH::from_bits(value))
+            }
+            RegisterAddress::INT1_TSH_ZL => {
+                Self::Int1ThresholdRegisterZL(Int1ThresholdRegisterZL::from_bits(value))
+            }
+            RegisterAddress::INT1_DURATION => {
+                Self::Int1DurationRegister(Int1DurationRegister::from_bits(value))
+            }
+        }
+    }
+
+    /// Returns the address of the register.
+    pub const fn address(&self) -> RegisterAddress {
+        match self {
+            Self::WhoAmI(_) => RegisterAddress::WHO_AM_I,
+            Self::ControlRegister1(_) => RegisterAddress::CTRL_REG1,
+            Self::ControlRegister2(_) => RegisterAddress::CTRL_REG2,
+            Self::ControlRegister3(_) => RegisterAddress::CTRL_REG3,
+            Self::ControlRegister4(_) => RegisterAddress::CTRL_REG4,
+            Self::ControlRegister5(_) => RegisterAddress::CTRL_REG5,
+            Self::ReferenceRegister(_) => RegisterAddress::REFERENCE,
+            Self::TemperatureRegister(_) => RegisterAddress::OUT_TEMP,
+            Self::StatusRegister(_) => RegisterAddress::STATUS_REG,
+            Self::OutXLow(_) => RegisterAddress::OUT_X_L,
+            Self::OutXHigh(_) => RegisterAddress::OUT_X_H,
+            Self::OutYLow(_) => RegisterAddress::OUT_Y_L,
+            Self::OutYHigh(_) => RegisterAddress::OUT_Y_H,
+            Self::OutZLow(_) => RegisterAddress::OUT_Z_L,
+            Self::OutZHigh(_) => RegisterAddress::OUT_Z_H,
+            Self::FifoControlRegister(_) => RegisterAddress::FIFO_CTRL_REG,
+            Self::FifoSourceRegister(_) => RegisterAddress::FIFO_SRC_REG,
+            Self::Int1ConfigurationRegister(_) => RegisterAddress::INT1_CFG,
+            Self::Int1SourceRegisterA(_) => RegisterAddress::INT1_SRC,
+            Self::Int1ThresholdRegisterXH(_) => RegisterAddress::INT1_TSH_XH,
+            Self::Int1ThresholdRegisterXL(_) => RegisterAddress::INT1_TSH_XL,
+            Self::Int1ThresholdRegisterYH(_) => RegisterAddress::INT1_TSH_YH,
+            Self::Int1ThresholdRegisterYL(_) => RegisterAddress::INT1_TSH_YL,
+            Self::Int1ThresholdRegisterZH(_) => RegisterAddress::INT1_TSH_ZH,
+            Self::Int1ThresholdRegisterZL(_) => RegisterAddress::INT1_TSH_ZL,
+            Self::Int1DurationRegister(_) => RegisterAddress::INT1_DURATION,
+        }
+    }
+
+    /// Returns the decoded register for formatting its fields.
+    fn fields(&self) -> &dyn core::fmt::Debug {
+        match self {
+            Self::WhoAmI(r) => r,
+            Self::ControlRegister1(r) => r,
+            Self::ControlRegister2(r) => r,
+            Self::ControlRegister3(r) => r,
+            Self::ControlRegister4(r) => r,
+            Self::ControlRegister5(r) => r,
+            Self::ReferenceRegister(r) => r,
+            Self::TemperatureRegister(r) => r,
+            Self::StatusRegister(r) => r,
+            Self::OutXLow(r) => r,
+            Self::OutXHigh(r) => r,
+            Self::OutYLow(r) => r,
+            Self::OutYHigh(r) => r,
+            Self::OutZLow(r) => r,
+            Self::OutZHigh(r) => r,
+            Self::FifoControlRegister(r) => r,
+            Self::FifoSourceRegister(r) => r,
+            Self::Int1ConfigurationRegister(r) => r,
+            Self::Int1SourceRegisterA(r) => r,
+            Self::Int1ThresholdRegisterXH(r) => r,
+            Self::Int1ThresholdRegisterXL(r) => r,
+            Self::Int1ThresholdRegisterYH(r) => r,
+            Self::Int1ThresholdRegisterYL(r) => r,
+            Self::Int1ThresholdRegisterZH(r) => r,
+            Self::Int1ThresholdRegisterZL(r) => r,
+            Self::Int1DurationRegister(r) => r,
+        }
+    }
+
+    /// Returns the byte value of the register.
+    pub const fn to_byte(&self) -> u8 {
+        match self {
+            Self::WhoAmI(r) => r.into_bits(),
+            Self::ControlRegister1(r) => r.into_bits(),
+            Self::ControlRegister2(r) => r.into_bits(),
+            Self::ControlRegister3(r) => r.into_bits(),
+            Self::ControlRegister4(r) => r.into_bits(),
+            Self::ControlRegister5(r) => r.into_bits(),
+            Self::ReferenceRegister(r) => r.into_bits(),
+            Self::TemperatureRegister(r) => r.into_bits(),
+            Self::StatusRegister(r) => r.into_bits(),
+            Self::OutXLow(r) => r.into_bits(),
+            Self::OutXHigh(r) => r.into_bits(),
+            Self::OutYLow(r) => r.into_bits(),
+            Self::OutYHigh(r) => r.into_bits(),
+            Self::OutZLow(r) => r.into_bits(),
+            Self::OutZHigh(r) => r.into_bits(),
+            Self::FifoControlRegister(r) => r.into_bits(),
+            Self::FifoSourceRegister(r) => r.into_bits(),
+            Self::Int1ConfigurationRegister(r) => r.into_bits(),
+            Self::Int1SourceRegisterA(r) => r.into_bits(),
+            Self::Int1ThresholdRegisterXH(r) => r.into_bits(),
+            Self::Int1ThresholdRegisterXL(r) => r.into_bits(),
+            Self::Int1ThresholdRegisterYH(r) => r.into_bits(),
+            Self::Int1ThresholdRegisterYL(r) => r.into_bits(),
+            Self::Int1ThresholdRegisterZH(r) => r.into_bits(),
+            Self::Int1ThresholdRegisterZL(r) => r.into_bits(),
+            Self::Int1DurationRegister(r) => r.into_bits(),
+        }
+    }
+}
+
+/// Renders a [`RegisterFile`] as an aligned table, e.g. for bring-up tools and test
+/// failure output. See [`RegisterFile::display`].
+///
+/// ```text
+/// ADDR  NAME           VALUE     FIELDS
+/// 0x20  CTRL_REG1      0x0F      ControlRegister1 { ... }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RegisterDumpDisplay<'a> {
+    file: &'a RegisterFile,
+}
+
+impl core::fmt::Display for RegisterDumpDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "{:<4}  {:<13}  {:<8}  FIELDS", "ADDR", "NAME", "VALUE")?;
+        for (address, value) in self.file {
+            writeln!(
+                f,
+                "{:#04x}  {:<13}  {:#04x}      {:?}",
+                address.addr(),
+                address.name(),
+                value.to_byte(),
+                value.fields()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes a raw register value into a human-readable description, e.g. for register
+/// decoders fed from logic analyzer captures.
+///
+/// Unknown addresses are reported as such rather than rejected.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_register(address: u8, value: u8) -> alloc::string::String {
+    match RegisterAddress::from_addr(address) {
+        Some(address) => alloc::format!("{:?}", RegisterValue::decode(address, value)),
+        None => alloc::format!("unknown register {address:#04x}: {value:#04x}"),
+    }
+}
+
+/// A register whose value deviates from its documented reset value.
+///
+/// See [`RegisterFile::reset_deviations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ResetDeviation {
+    /// The register address.
+    pub address: RegisterAddress,
+    /// The documented reset value.
+    pub expected: u8,
+    /// The actual value.
+    pub actual: u8,
+}
+
+impl ResetDeviation {
+    /// Returns the bits that differ from the reset value.
+    pub const fn mask(&self) -> u8 {
+        self.expected ^ self.actual
+    }
+
+    /// Iterates the fields that differ from the reset value, from the most significant bit.
+    pub fn fields(&self) -> DeviatingFields {
+        DeviatingFields::new(self.address.fields(), self.mask())
+    }
+}
+
+/// A register whose value differs between two snapshots.
+///
+/// See [`RegisterFile::changes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RegisterChange {
+    /// The register address.
+    pub address: RegisterAddress,
+    /// The value in the first snapshot.
+    pub before: u8,
+    /// The value in the second snapshot.
+    pub after: u8,
+}
+
+impl RegisterChange {
+    /// Returns the bits that changed.
+    pub const fn mask(&self) -> u8 {
+        self.before ^ self.after
+    }
+}
+
+/// Decodes a dump of the contiguous block `CTRL_REG1` (20h) through `INT1_DURATION` (38h).
+///
+/// The slice must be exactly [`RegisterFile::BLOCK_LEN`] bytes long. To decode dumps
+/// starting at a different address, use [`RegisterFile::from_dump`].
+impl TryFrom<&[u8]> for RegisterFile {
+    type Error = DumpError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() != Self::BLOCK_LEN {
+            return Err(DumpError::InvalidLength {
+                expected: Self::BLOCK_LEN,
+                actual: value.len(),
+            });
+        }
+
+        Self::from_dump(Self::BLOCK_START.addr(), value)
+    }
+}
+
+/// An error when decoding a register dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpError {
+    /// The dump starts after [`CTRL_REG1`](RegisterAddress::CTRL_REG1) and
+    /// cannot cover the contiguous register block.
+    InvalidStartAddress(u8),
+    /// The dump does not have the required number of bytes.
+    InvalidLength {
+        /// The number of bytes required.
+        expected: usize,
+        /// The number of bytes provided.
+        actual: usize,
+    },
+}
+
+impl core::fmt::Display for DumpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DumpError::InvalidStartAddress(address) => write!(
+                f,
+                "register dump starting at {address:02X}h does not cover CTRL_REG1 (20h)"
+            ),
+            DumpError::InvalidLength { expected, actual } => write!(
+                f,
+                "register dump has {actual} bytes, but {expected} bytes are required"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DumpError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldDescriptor, FifoMode};
+
+    #[test]
+    fn decode_is_total_and_lossless() {
+        for address in RegisterAddress::ALL {
+            for value in 0..=u8::MAX {
+                let decoded = RegisterValue::decode(address, value);
+                assert_eq!(decoded.address(), address);
+                assert_eq!(decoded.to_byte(), value);
+            }
+        }
+    }
+
+    #[test]
+    fn reserved_fifo_mode_is_detected() {
+        for value in [0xA0, 0xC0, 0xE0] {
+            let register = FifoControlRegister::from_bits(value);
+            assert_eq!(register.try_fifo_mode(), None);
+            assert_eq!(register.fifo_mode(), FifoMode::Bypass);
+        }
+        let register = FifoControlRegister::from_bits(0x80);
+        assert_eq!(register.try_fifo_mode(), Some(FifoMode::BypassToStream));
+    }
+
+    /// Counts the formatted bytes, so that formatting can be tested without allocating.
+    struct Sink(usize);
+
+    impl core::fmt::Write for Sink {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn display_accepts_reserved_fifo_mode() {
+        use core::fmt::Write;
+
+        let mut block = [0; RegisterFile::BLOCK_LEN];
+        block[usize::from(
+            RegisterAddress::FIFO_CTRL_REG.addr() - RegisterFile::BLOCK_START.addr(),
+        )] = 0xE0;
+        let file = RegisterFile::from_block(&block);
+        assert_eq!(file.byte_at(RegisterAddress::FIFO_CTRL_REG), Some(0xE0));
+
+        let mut sink = Sink(0);
+        write!(sink, "{}", file.display()).unwrap();
+        assert!(sink.0 > 0);
+    }
+
+    #[test]
+    fn any_dump_decodes_and_formats() {
+        use core::fmt::Write;
+
+        for value in 0..=u8::MAX {
+            let block = [value; RegisterFile::BLOCK_LEN];
+            assert_eq!(
+                RegisterFile::try_from(&block[..]).unwrap().to_block(),
+                block
+            );
+
+            let dump = [value; 0x38 - 0x0F + 1];
+            let file = RegisterFile::from_dump(RegisterAddress::WHO_AM_I.addr(), &dump).unwrap();
+            assert_eq!(file.byte_at(RegisterAddress::WHO_AM_I), Some(value));
+
+            let mut sink = Sink(0);
+            for (address, decoded) in &file {
+                assert_eq!(file.byte_at(address), Some(decoded.to_byte()));
+                write!(sink, "{decoded:?}").unwrap();
+            }
+            assert!(sink.0 > 0);
+        }
+    }
+
+    /// Returns the block holding the documented reset values.
+    fn reset_block() -> [u8; RegisterFile::BLOCK_LEN] {
+        let mut block = [0; RegisterFile::BLOCK_LEN];
+        for (address, byte) in (RegisterFile::BLOCK_START.addr()..).zip(block.iter_mut()) {
+            let address = RegisterAddress::from_addr(address).unwrap();
+            *byte = address.reset_value().unwrap_or_default();
+        }
+        block
+    }
+
+    #[test]
+    fn from_dump_validates_the_range() {
+        assert_eq!(
+            RegisterFile::from_dump(0x21, &[0; 32]),
+            Err(DumpError::InvalidStartAddress(0x21))
+        );
+        assert_eq!(
+            RegisterFile::from_dump(0x1F, &[0; RegisterFile::BLOCK_LEN]),
+            Err(DumpError::InvalidLength {
+                expected: RegisterFile::BLOCK_LEN + 1,
+                actual: RegisterFile::BLOCK_LEN,
+            })
+        );
+        assert_eq!(
+            RegisterFile::try_from(&[0; 4][..]),
+            Err(DumpError::InvalidLength {
+                expected: RegisterFile::BLOCK_LEN,
+                actual: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn from_dump_applies_the_start_address() {
+        let mut dump = [0; 0x40];
+        for (index, byte) in dump.iter_mut().enumerate() {
+            *byte = index as u8;
+        }
+
+        let file = RegisterFile::from_dump(0x00, &dump).unwrap();
+        assert_eq!(file.byte_at(RegisterAddress::WHO_AM_I), Some(0x0F));
+        assert_eq!(file.byte_at(RegisterAddress::CTRL_REG1), Some(0x20));
+        assert_eq!(file.byte_at(RegisterAddress::INT1_DURATION), Some(0x38));
+
+        let file = RegisterFile::from_dump(0x10, &dump[0x10..]).unwrap();
+        assert_eq!(file.byte_at(RegisterAddress::WHO_AM_I), None);
+        assert_eq!(file.byte_at(RegisterAddress::CTRL_REG1), Some(0x20));
+        assert!(file
+            .iter()
+            .all(|(address, _)| address != RegisterAddress::WHO_AM_I));
+    }
+
+    #[test]
+    fn reset_deviations_report_changed_fields() {
+        let mut block = reset_block();
+        assert!(RegisterFile::from_block(&block).is_default());
+
+        block[0] = 0x0F;
+        let file = RegisterFile::from_block(&block);
+        assert!(!file.is_default());
+
+        let mut deviations = file.reset_deviations();
+        let deviation = deviations.next().unwrap();
+        assert_eq!(deviation.address, RegisterAddress::CTRL_REG1);
+        assert_eq!(deviation.mask(), 0b0000_1000);
+        let mut fields = deviation.fields();
+        assert_eq!(fields.next().map(FieldDescriptor::name), Some("power_up"));
+        assert_eq!(fields.next(), None);
+        assert_eq!(deviations.next(), None);
+    }
+
+    #[test]
+    fn changes_compare_snapshots() {
+        let before = RegisterFile::from_block(&reset_block());
+        let mut block = reset_block();
+        block[usize::from(RegisterAddress::CTRL_REG4.addr() - 0x20)] = 0x10;
+        let after = RegisterFile::from_block(&block);
+
+        let mut changes = before.changes(&after);
+        assert_eq!(
+            changes.next(),
+            Some(RegisterChange {
+                address: RegisterAddress::CTRL_REG4,
+                before: 0x00,
+                after: 0x10,
+            })
+        );
+        assert_eq!(changes.next(), None);
+        assert_eq!(before.changes(&before).count(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_register_describes_the_value() {
+        assert_eq!(decode_register(0x10, 0x05), "unknown register 0x10: 0x05");
+        let decoded = decode_register(0x23, 0x10);
+        assert!(decoded.starts_with("ControlRegister4("));
+        assert!(decoded.contains("D500"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_register_accepts_any_byte() {
+        for address in 0..=u8::MAX {
+            for value in 0..=u8::MAX {
+                assert!(!decode_register(address, value).is_empty());
+            }
+        }
+    }
+}
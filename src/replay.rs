@@ -0,0 +1,174 @@
+//! Playback of recorded bus traffic.
+
+use crate::{RegisterAddress, RegisterBus};
+
+/// The direction of a [`BusTransaction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Direction {
+    /// Registers were read from the device.
+    Read,
+    /// Registers were written to the device.
+    Write,
+}
+
+/// A recorded register transfer, e.g. decoded from a logic analyzer export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BusTransaction<'a> {
+    /// The direction of the transfer.
+    pub direction: Direction,
+    /// The first register of the transfer.
+    pub start: RegisterAddress,
+    /// The bytes read or written, in register order.
+    pub data: &'a [u8],
+}
+
+impl<'a> BusTransaction<'a> {
+    /// Creates a recorded read.
+    pub const fn read(start: RegisterAddress, data: &'a [u8]) -> Self {
+        Self {
+            direction: Direction::Read,
+            start,
+            data,
+        }
+    }
+
+    /// Creates a recorded write.
+    pub const fn write(start: RegisterAddress, data: &'a [u8]) -> Self {
+        Self {
+            direction: Direction::Write,
+            start,
+            data,
+        }
+    }
+}
+
+/// A [`RegisterBus`] that plays back a recorded transaction log, e.g. to reproduce a field
+/// failure in a regression test.
+///
+/// Every transfer must match the next transaction of the log in direction, start register
+/// and length; writes must also match the recorded data. Reads return the recorded data.
+#[derive(Debug, Clone)]
+pub struct Replay<'a> {
+    log: &'a [BusTransaction<'a>],
+    position: usize,
+}
+
+impl<'a> Replay<'a> {
+    /// Creates a transport playing back the log from the start.
+    pub const fn new(log: &'a [BusTransaction<'a>]) -> Self {
+        Self { log, position: 0 }
+    }
+
+    /// Returns the index of the next transaction to be played back.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the transactions not yet played back.
+    pub fn remaining(&self) -> &'a [BusTransaction<'a>] {
+        &self.log[self.position..]
+    }
+
+    /// Determines whether the whole log was played back.
+    pub const fn is_finished(&self) -> bool {
+        self.position >= self.log.len()
+    }
+
+    /// Restarts the playback from the start of the log.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    fn next(
+        &mut self,
+        direction: Direction,
+        start: RegisterAddress,
+        len: usize,
+    ) -> Result<&'a BusTransaction<'a>, ReplayError> {
+        let index = self.position;
+        let transaction = self.log.get(index).ok_or(ReplayError::Exhausted)?;
+        if transaction.direction != direction
+            || transaction.start != start
+            || transaction.data.len() != len
+        {
+            return Err(ReplayError::Mismatch {
+                index,
+                direction,
+                start,
+            });
+        }
+        self.position += 1;
+        Ok(transaction)
+    }
+}
+
+impl RegisterBus for Replay<'_> {
+    type Error = ReplayError;
+
+    fn read_registers(
+        &mut self,
+        start: RegisterAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let transaction = self.next(Direction::Read, start, buffer.len())?;
+        buffer.copy_from_slice(transaction.data);
+        Ok(())
+    }
+
+    fn write_registers(
+        &mut self,
+        start: RegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::Error> {
+        let index = self.position;
+        let transaction = self.next(Direction::Write, start, values.len())?;
+        if transaction.data != values {
+            self.position = index;
+            return Err(ReplayError::Mismatch {
+                index,
+                direction: Direction::Write,
+                start,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// An error when a transfer deviates from the log played back by [`Replay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    /// All transactions were played back already.
+    Exhausted,
+    /// The transfer does not match the recorded transaction.
+    Mismatch {
+        /// The index of the recorded transaction.
+        index: usize,
+        /// The direction of the attempted transfer.
+        direction: Direction,
+        /// The start register of the attempted transfer.
+        start: RegisterAddress,
+    },
+}
+
+impl core::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReplayError::Exhausted => f.write_str("transaction log exhausted"),
+            ReplayError::Mismatch {
+                index,
+                direction,
+                start,
+            } => write!(
+                f,
+                "{direction:?} at {} does not match recorded transaction {index}",
+                start.name()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ReplayError {}
@@ -0,0 +1,161 @@
+//! Retrying of bus transfers and recovery after bus upsets.
+
+use crate::{Config, DetectedChip, RegisterAddress, RegisterBus};
+
+/// A [`RegisterBus`] wrapper retrying failed transfers, e.g. after I²C arbitration loss or
+/// a missing acknowledge.
+///
+/// Every transfer is attempted up to `retries + 1` times, calling the delay between attempts.
+/// The error of the last attempt is returned.
+///
+/// Reading the output registers consumes a FIFO frame; a failed read that reached the sensor
+/// before failing may therefore skip a frame when retried.
+#[derive(Debug, Clone)]
+pub struct Retry<B, D = fn()> {
+    bus: B,
+    retries: u8,
+    delay: D,
+    retried: u32,
+}
+
+impl<B> Retry<B> {
+    /// Wraps a bus, retrying failed transfers up to `retries` times without delay.
+    pub const fn new(bus: B, retries: u8) -> Self {
+        Self {
+            bus,
+            retries,
+            delay: no_delay,
+            retried: 0,
+        }
+    }
+}
+
+impl<B, D> Retry<B, D>
+where
+    D: FnMut(),
+{
+    /// Sets the delay called between attempts, e.g. a closure busy-waiting for a millisecond.
+    pub fn with_delay<T>(self, delay: T) -> Retry<B, T>
+    where
+        T: FnMut(),
+    {
+        Retry {
+            bus: self.bus,
+            retries: self.retries,
+            delay,
+            retried: self.retried,
+        }
+    }
+
+    /// Returns the number of retries per transfer.
+    pub const fn retries(&self) -> u8 {
+        self.retries
+    }
+
+    /// Returns the number of retries performed so far.
+    pub const fn retried(&self) -> u32 {
+        self.retried
+    }
+
+    /// Returns the wrapped bus.
+    pub fn into_inner(self) -> B {
+        self.bus
+    }
+
+    fn attempt<T, E, F>(&mut self, mut transfer: F) -> Result<T, E>
+    where
+        F: FnMut(&mut B) -> Result<T, E>,
+    {
+        let mut remaining = self.retries;
+        loop {
+            match transfer(&mut self.bus) {
+                Err(_) if remaining > 0 => {
+                    remaining -= 1;
+                    self.retried = self.retried.saturating_add(1);
+                    (self.delay)();
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<B, D> RegisterBus for Retry<B, D>
+where
+    B: RegisterBus,
+    D: FnMut(),
+{
+    type Error = B::Error;
+
+    fn read_registers(
+        &mut self,
+        start: RegisterAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.attempt(|bus| bus.read_registers(start, buffer))
+    }
+
+    fn write_registers(
+        &mut self,
+        start: RegisterAddress,
+        values: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.attempt(|bus| bus.write_registers(start, values))
+    }
+}
+
+fn no_delay() {}
+
+/// Restores the sensor after a bus upset or brown-out.
+///
+/// Verifies [`WHO_AM_I`](RegisterAddress::WHO_AM_I) using [`DetectedChip::from_who_am_i`]
+/// and, if a known chip responds, writes `config` using [`Config::write_sequence`].
+/// Combine with [`Retry`] to tolerate transient errors during recovery.
+///
+/// Returns the detected chip.
+pub fn recover<B>(mut bus: B, config: &Config) -> Result<DetectedChip, RecoverError<B::Error>>
+where
+    B: RegisterBus,
+{
+    let who_am_i = bus
+        .read_register(RegisterAddress::WHO_AM_I)
+        .map_err(RecoverError::Bus)?;
+    let chip =
+        DetectedChip::from_who_am_i(who_am_i).ok_or(RecoverError::UnknownChip { who_am_i })?;
+
+    for (register, value) in config.write_sequence() {
+        bus.write_register(register, value)
+            .map_err(RecoverError::Bus)?;
+    }
+    Ok(chip)
+}
+
+/// An error when restoring the sensor using [`recover`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoverError<E> {
+    /// The bus reported an error.
+    Bus(E),
+    /// The device reported an unknown `WHO_AM_I` value.
+    UnknownChip {
+        /// The value read from `WHO_AM_I`.
+        who_am_i: u8,
+    },
+}
+
+impl<E> core::fmt::Display for RecoverError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RecoverError::Bus(error) => write!(f, "bus error: {error}"),
+            RecoverError::UnknownChip { who_am_i } => {
+                write!(f, "device reported unknown WHO_AM_I value {who_am_i:02X}h")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<E> std::error::Error for RecoverError<E> where E: core::fmt::Debug + core::fmt::Display {}
@@ -0,0 +1,93 @@
+//! Poll scheduling for firmware that does not use the DRDY signal.
+
+use crate::OutputDataRate;
+
+/// Computes when to poll the sensor for new data at the configured [`OutputDataRate`].
+///
+/// The schedule tracks the time of the last observed sample and aims the next poll at the
+/// time the following sample is expected, plus a margin that absorbs timer jitter and
+/// oscillator tolerance. If a poll finds no new data, the next poll is scheduled one margin
+/// later.
+///
+/// For fixed-rate polling, use [`poll_interval_us`](Self::poll_interval_us) instead, which
+/// is shorter than the sample period by the margin so that no sample is skipped.
+///
+/// Timestamps are provided by the caller in microseconds from an arbitrary, monotonic clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SamplingSchedule {
+    period_us: u32,
+    margin_us: u32,
+    last_sample_us: Option<u64>,
+}
+
+impl SamplingSchedule {
+    /// The default margin as a fraction of the sample period, i.e. one eighth.
+    pub const DEFAULT_MARGIN_DIVISOR: u32 = 8;
+
+    /// Creates a schedule for the specified output data rate with the default margin.
+    pub const fn new(odr: OutputDataRate) -> Self {
+        let period_us = odr.period_us();
+        Self {
+            period_us,
+            margin_us: period_us / Self::DEFAULT_MARGIN_DIVISOR,
+            last_sample_us: None,
+        }
+    }
+
+    /// Sets the jitter margin in microseconds. The margin is limited to half a sample period.
+    #[must_use]
+    pub const fn with_margin_us(mut self, margin_us: u32) -> Self {
+        let max = self.period_us / 2;
+        self.margin_us = if margin_us > max { max } else { margin_us };
+        self
+    }
+
+    /// Returns the sample period in microseconds.
+    pub const fn period_us(&self) -> u32 {
+        self.period_us
+    }
+
+    /// Returns the jitter margin in microseconds.
+    pub const fn margin_us(&self) -> u32 {
+        self.margin_us
+    }
+
+    /// Returns the interval for fixed-rate polling in microseconds, i.e. the sample period
+    /// reduced by the margin.
+    pub const fn poll_interval_us(&self) -> u32 {
+        self.period_us - self.margin_us
+    }
+
+    /// Records that a poll found new data.
+    pub fn record_sample(&mut self, now_us: u64) {
+        self.last_sample_us = Some(now_us);
+    }
+
+    /// Forgets the last sample, e.g. after reconfiguring the sensor.
+    pub fn reset(&mut self) {
+        self.last_sample_us = None;
+    }
+
+    /// Returns the time of the next poll in microseconds.
+    ///
+    /// Without a recorded sample, the next poll is due immediately.
+    pub fn next_poll_us(&self, now_us: u64) -> u64 {
+        match self.last_sample_us {
+            Some(last) => {
+                let expected = last + self.period_us as u64 + self.margin_us as u64;
+                if expected > now_us {
+                    expected
+                } else {
+                    now_us + self.margin_us as u64
+                }
+            }
+            None => now_us,
+        }
+    }
+
+    /// Returns the time until the next poll in microseconds.
+    pub fn delay_us(&self, now_us: u64) -> u64 {
+        self.next_poll_us(now_us) - now_us
+    }
+}
@@ -0,0 +1,219 @@
+//! Recording of measurements, e.g. for offline analysis.
+
+use crate::{Measurement, TimestampedMeasurement};
+
+/// A destination for timestamped measurements, such as a log file or a buffer.
+pub trait SampleSink {
+    /// The error type of the sink.
+    type Error;
+
+    /// Records a single measurement.
+    fn push(&mut self, sample: &TimestampedMeasurement) -> Result<(), Self::Error>;
+
+    /// Records all measurements, stopping at the first error.
+    fn push_all<I>(&mut self, samples: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = TimestampedMeasurement>,
+    {
+        samples
+            .into_iter()
+            .try_for_each(|sample| self.push(&sample))
+    }
+
+    /// Flushes buffered measurements. Does nothing by default.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<S> SampleSink for &mut S
+where
+    S: SampleSink + ?Sized,
+{
+    type Error = S::Error;
+
+    fn push(&mut self, sample: &TimestampedMeasurement) -> Result<(), Self::Error> {
+        (**self).push(sample)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        (**self).flush()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl SampleSink for alloc::vec::Vec<TimestampedMeasurement> {
+    type Error = core::convert::Infallible;
+
+    fn push(&mut self, sample: &TimestampedMeasurement) -> Result<(), Self::Error> {
+        alloc::vec::Vec::push(self, *sample);
+        Ok(())
+    }
+}
+
+/// The binary record format written by `BinarySink` (requires the `std` feature).
+///
+/// Each record starts with a length byte giving the number of bytes that follow, currently
+/// [`PAYLOAD_LEN`](Self::PAYLOAD_LEN). The payload holds the timestamp as a little endian
+/// `u64`, followed by the measurement as returned by [`Measurement::to_le_bytes`].
+/// Readers skip trailing payload bytes they do not understand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BinaryRecord;
+
+impl BinaryRecord {
+    /// The number of payload bytes following the length byte.
+    pub const PAYLOAD_LEN: usize = 8 + Measurement::LEN;
+
+    /// The total length of a record, including the length byte.
+    pub const LEN: usize = 1 + Self::PAYLOAD_LEN;
+
+    /// Encodes a measurement into a record.
+    pub const fn encode(sample: &TimestampedMeasurement) -> [u8; Self::LEN] {
+        let timestamp = sample.timestamp_us.to_le_bytes();
+        let measurement = sample.measurement.to_le_bytes();
+        let mut record = [0; Self::LEN];
+        record[0] = Self::PAYLOAD_LEN as u8;
+        let mut i = 0;
+        while i < 8 {
+            record[1 + i] = timestamp[i];
+            record[9 + i] = measurement[i];
+            i += 1;
+        }
+        record
+    }
+
+    /// Decodes the record at the start of the data.
+    ///
+    /// Returns the measurement and the number of bytes consumed, or `None` if the data
+    /// does not hold a complete record.
+    pub fn decode(data: &[u8]) -> Option<(TimestampedMeasurement, usize)> {
+        let len = usize::from(*data.first()?);
+        if len < Self::PAYLOAD_LEN || data.len() < 1 + len {
+            return None;
+        }
+        let mut timestamp = [0; 8];
+        timestamp.copy_from_slice(&data[1..9]);
+        let mut measurement = [0; Measurement::LEN];
+        measurement.copy_from_slice(&data[9..9 + Measurement::LEN]);
+        Some((
+            TimestampedMeasurement::new(
+                u64::from_le_bytes(timestamp),
+                Measurement::from_le_bytes(measurement),
+            ),
+            1 + len,
+        ))
+    }
+}
+
+/// Writes measurements as comma-separated values, one row per measurement.
+///
+/// The columns are `timestamp_us,x,y,z,temp,status` with the raw values in digits.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct CsvSink<W> {
+    writer: W,
+    header: bool,
+}
+
+#[cfg(feature = "std")]
+impl<W> CsvSink<W>
+where
+    W: std::io::Write,
+{
+    /// The header row, without line terminator.
+    pub const HEADER: &'static str = "timestamp_us,x,y,z,temp,status";
+
+    /// Creates a sink writing the header row before the first measurement.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            header: true,
+        }
+    }
+
+    /// Creates a sink that does not write a header row, e.g. when appending to a file.
+    pub fn without_header(writer: W) -> Self {
+        Self {
+            writer,
+            header: false,
+        }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> SampleSink for CsvSink<W>
+where
+    W: std::io::Write,
+{
+    type Error = std::io::Error;
+
+    fn push(&mut self, sample: &TimestampedMeasurement) -> Result<(), Self::Error> {
+        if self.header {
+            writeln!(self.writer, "{}", Self::HEADER)?;
+            self.header = false;
+        }
+        let measurement = &sample.measurement;
+        writeln!(
+            self.writer,
+            "{},{},{},{},{},{}",
+            sample.timestamp_us,
+            measurement.rate.x,
+            measurement.rate.y,
+            measurement.rate.z,
+            measurement.temp,
+            measurement.status.into_bits()
+        )
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+/// Writes measurements as length-prefixed [`BinaryRecord`]s.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct BinarySink<W> {
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<W> BinarySink<W>
+where
+    W: std::io::Write,
+{
+    /// Creates a new sink.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W> SampleSink for BinarySink<W>
+where
+    W: std::io::Write,
+{
+    type Error = std::io::Error;
+
+    fn push(&mut self, sample: &TimestampedMeasurement) -> Result<(), Self::Error> {
+        self.writer.write_all(&BinaryRecord::encode(sample))
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,421 @@
+//! Sample statistics, e.g. for noise characterization and bias estimation.
+
+use crate::units::sqrt;
+use crate::{AngularRate, AngularRateRaw, Sensitivity};
+
+/// Running mean, variance, minimum and maximum of a series of samples.
+///
+/// Uses Welford's algorithm, which is numerically stable and requires constant memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RunningStats {
+    count: u32,
+    mean: f32,
+    m2: f32,
+    min: f32,
+    max: f32,
+}
+
+impl RunningStats {
+    /// Creates an empty accumulator.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f32::INFINITY,
+            max: f32::NEG_INFINITY,
+        }
+    }
+
+    /// Adds a sample.
+    pub fn push(&mut self, value: f32) {
+        self.count = self.count.saturating_add(1);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f32;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Removes all samples.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of samples.
+    pub const fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns the mean, or `None` if there are no samples.
+    pub fn mean(&self) -> Option<f32> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the sample variance, or `None` if there are fewer than two samples.
+    pub fn variance(&self) -> Option<f32> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f32)
+    }
+
+    /// Returns the sample standard deviation, or `None` if there are fewer than two samples.
+    pub fn std_dev(&self) -> Option<f32> {
+        self.variance().map(sqrt)
+    }
+
+    /// Returns the smallest sample, or `None` if there are no samples.
+    pub fn min(&self) -> Option<f32> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the largest sample, or `None` if there are no samples.
+    pub fn max(&self) -> Option<f32> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<f32> for RunningStats {
+    fn extend<T: IntoIterator<Item = f32>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// [`RunningStats`] of all three axes.
+///
+/// Raw and scaled samples should not be mixed, as the statistics are kept in the unit of the
+/// samples, i.e. digits or degrees per second.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RateStats {
+    /// X-axis statistics.
+    pub x: RunningStats,
+    /// Y-axis statistics.
+    pub y: RunningStats,
+    /// Z-axis statistics.
+    pub z: RunningStats,
+}
+
+impl RateStats {
+    /// Creates an empty accumulator.
+    pub const fn new() -> Self {
+        Self {
+            x: RunningStats::new(),
+            y: RunningStats::new(),
+            z: RunningStats::new(),
+        }
+    }
+
+    /// Adds a raw sample, in digits.
+    pub fn push_raw(&mut self, raw: AngularRateRaw) {
+        self.x.push(raw.x as f32);
+        self.y.push(raw.y as f32);
+        self.z.push(raw.z as f32);
+    }
+
+    /// Adds a scaled sample, in degrees per second.
+    pub fn push(&mut self, rate: AngularRate) {
+        self.x.push(rate.x());
+        self.y.push(rate.y());
+        self.z.push(rate.z());
+    }
+
+    /// Removes all samples.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the number of samples.
+    pub const fn count(&self) -> u32 {
+        self.x.count()
+    }
+
+    /// Returns the mean of all three axes, e.g. the zero-rate bias of a stationary sensor
+    /// for [`CalibrationProfile::with_bias`](crate::CalibrationProfile::with_bias).
+    pub fn mean(&self) -> Option<[f32; 3]> {
+        Some([self.x.mean()?, self.y.mean()?, self.z.mean()?])
+    }
+
+    /// Returns the sample variance of all three axes.
+    pub fn variance(&self) -> Option<[f32; 3]> {
+        Some([self.x.variance()?, self.y.variance()?, self.z.variance()?])
+    }
+
+    /// Returns the sample standard deviation of all three axes.
+    pub fn std_dev(&self) -> Option<[f32; 3]> {
+        Some([self.x.std_dev()?, self.y.std_dev()?, self.z.std_dev()?])
+    }
+}
+
+/// Computes the overlapping Allan deviation of rate samples for a cluster of `cluster_size`
+/// samples, i.e. an averaging time of `cluster_size` times the sample period.
+///
+/// The result is in the unit of the samples, e.g. degrees per second. Returns `None` if
+/// `cluster_size` is zero or exceeds half the number of samples.
+pub fn allan_deviation_at(samples: &[f32], cluster_size: usize) -> Option<f32> {
+    let m = cluster_size;
+    if m == 0 || m > samples.len() / 2 {
+        return None;
+    }
+
+    // Sliding sums of two adjacent clusters.
+    let mut first: f64 = samples[..m].iter().map(|&v| v as f64).sum();
+    let mut second: f64 = samples[m..2 * m].iter().map(|&v| v as f64).sum();
+
+    let terms = samples.len() - 2 * m + 1;
+    let mut sum = 0.0;
+    for k in 0..terms {
+        let diff = second - first;
+        sum += diff * diff;
+        if k + 1 < terms {
+            first += (samples[k + m] - samples[k]) as f64;
+            second += (samples[k + 2 * m] - samples[k + m]) as f64;
+        }
+    }
+
+    let variance = sum / (2.0 * (m * m) as f64 * terms as f64);
+    Some(sqrt(variance as f32))
+}
+
+/// A point of an Allan deviation curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AllanPoint {
+    /// The averaging time in seconds.
+    pub tau: f32,
+    /// The Allan deviation in the unit of the samples.
+    pub deviation: f32,
+}
+
+/// Computes the overlapping Allan deviation curve of rate samples recorded from a stationary
+/// sensor at octave-spaced averaging times.
+///
+/// `sample_period` is the time between samples in seconds, i.e. the inverse of the output
+/// data rate. The angle random walk can be read from the curve at `tau = 1 s`, the bias
+/// instability from its minimum.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn allan_deviation(samples: &[f32], sample_period: f32) -> alloc::vec::Vec<AllanPoint> {
+    core::iter::successors(Some(1usize), |m| m.checked_mul(2))
+        .map_while(|m| {
+            allan_deviation_at(samples, m).map(|deviation| AllanPoint {
+                tau: m as f32 * sample_period,
+                deviation,
+            })
+        })
+        .collect()
+}
+
+/// Detects whether the sensor is stationary from the variance of raw readings
+/// over a sliding window of `N` samples.
+///
+/// Use it to gate bias estimation so that auto-zeroing only runs while the platform is
+/// actually at rest, e.g. by only pushing samples into [`RateStats`] while
+/// [`observe`](Self::observe) returns `true`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StillnessDetector<const N: usize> {
+    window: [[i16; 3]; N],
+    index: usize,
+    len: usize,
+    sum: [i64; 3],
+    sum_squares: [i64; 3],
+    max_variance: u32,
+}
+
+impl<const N: usize> StillnessDetector<N> {
+    const VALID_SIZE: () = assert!(N >= 2, "the window must hold at least two samples");
+
+    /// Creates a detector considering the sensor stationary while the standard deviation of
+    /// every axis stays at or below `max_std_dev` digits.
+    pub const fn new(max_std_dev: u16) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SIZE;
+        Self {
+            window: [[0; 3]; N],
+            index: 0,
+            len: 0,
+            sum: [0; 3],
+            sum_squares: [0; 3],
+            max_variance: max_std_dev as u32 * max_std_dev as u32,
+        }
+    }
+
+    /// Creates a detector with the threshold in degrees per second at the specified sensitivity.
+    pub fn from_dps(max_std_dev: f32, sensitivity: Sensitivity) -> Self {
+        let digits = max_std_dev * 1000.0 / sensitivity.mdps_per_digit();
+        Self::new(digits.clamp(0.0, u16::MAX as f32) as u16)
+    }
+
+    /// Adds a reading and determines whether the sensor is stationary.
+    ///
+    /// Returns `false` until the window is filled.
+    pub fn observe(&mut self, raw: AngularRateRaw) -> bool {
+        let sample = [raw.x, raw.y, raw.z];
+        let evicted = self.window[self.index];
+        let full = self.len == N;
+        for axis in 0..3 {
+            let value = sample[axis] as i64;
+            self.sum[axis] += value;
+            self.sum_squares[axis] += value * value;
+            if full {
+                let old = evicted[axis] as i64;
+                self.sum[axis] -= old;
+                self.sum_squares[axis] -= old * old;
+            }
+        }
+        self.window[self.index] = sample;
+        self.index = (self.index + 1) % N;
+        self.len = (self.len + 1).min(N);
+        self.is_still()
+    }
+
+    /// Determines whether the sensor is stationary based on the current window.
+    pub fn is_still(&self) -> bool {
+        if self.len < N {
+            return false;
+        }
+        let n = N as i64;
+        (0..3).all(|axis| {
+            // (N - 1) * N * variance, computed exactly.
+            let scaled = n * self.sum_squares[axis] - self.sum[axis] * self.sum[axis];
+            scaled <= (n - 1) * n * self.max_variance as i64
+        })
+    }
+
+    /// Returns the mean of the window in digits, or `None` until the window is filled.
+    pub fn mean(&self) -> Option<[f32; 3]> {
+        (self.len == N).then(|| self.sum.map(|sum| sum as f32 / N as f32))
+    }
+
+    /// Clears the window.
+    pub fn reset(&mut self) {
+        *self = Self::new(0).with_max_variance(self.max_variance);
+    }
+
+    const fn with_max_variance(mut self, max_variance: u32) -> Self {
+        self.max_variance = max_variance;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deterministic uniform noise source in `[-1, 1)` (xorshift32).
+    fn noise(count: usize) -> impl Iterator<Item = f32> {
+        let mut state = 0x2545_F491_u32;
+        core::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1u32 << 23) as f32 - 1.0
+        })
+        .take(count)
+    }
+
+    #[test]
+    fn running_stats_known_answer() {
+        let mut stats = RunningStats::new();
+        stats.extend([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.mean(), Some(5.0));
+        assert!((stats.variance().unwrap() - 32.0 / 7.0).abs() < 1e-5);
+        assert_eq!(stats.min(), Some(2.0));
+        assert_eq!(stats.max(), Some(9.0));
+    }
+
+    #[test]
+    fn running_stats_constant_input_has_zero_variance() {
+        let mut stats = RunningStats::new();
+        stats.extend(core::iter::repeat(3.25).take(1000));
+        assert_eq!(stats.mean(), Some(3.25));
+        assert_eq!(stats.variance(), Some(0.0));
+    }
+
+    #[test]
+    fn running_stats_requires_samples() {
+        let mut stats = RunningStats::new();
+        assert_eq!(stats.mean(), None);
+        stats.push(1.0);
+        assert_eq!(stats.variance(), None);
+    }
+
+    #[test]
+    fn allan_deviation_rejects_invalid_cluster_sizes() {
+        let samples = [0.0; 8];
+        assert_eq!(allan_deviation_at(&samples, 0), None);
+        assert_eq!(allan_deviation_at(&samples, 5), None);
+        assert_eq!(allan_deviation_at(&samples, usize::MAX), None);
+        assert!(allan_deviation_at(&samples, 4).is_some());
+    }
+
+    #[test]
+    fn allan_deviation_of_constant_input_is_zero() {
+        let samples = [1.5; 64];
+        for m in [1, 2, 4, 8, 16, 32] {
+            assert_eq!(allan_deviation_at(&samples, m), Some(0.0));
+        }
+    }
+
+    #[test]
+    fn allan_deviation_known_answer() {
+        // Adjacent single-sample clusters always differ by 2, so the Allan variance is 4 / 2.
+        let samples = [1.0, -1.0, 1.0, -1.0];
+        let deviation = allan_deviation_at(&samples, 1).unwrap();
+        assert!((deviation - core::f32::consts::SQRT_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn allan_deviation_of_white_noise_has_slope_minus_one_half() {
+        let mut samples = [0.0; 1 << 16];
+        for (sample, value) in samples.iter_mut().zip(noise(1 << 16)) {
+            *sample = value;
+        }
+        let short = allan_deviation_at(&samples, 4).unwrap();
+        let long = allan_deviation_at(&samples, 64).unwrap();
+        // A 16 times longer averaging time reduces the deviation by a factor of 4.
+        let ratio = long / short;
+        assert!((ratio - 0.25).abs() < 0.03, "ratio {ratio}");
+    }
+
+    #[test]
+    fn stillness_detector_requires_a_full_window() {
+        let mut detector = StillnessDetector::<4>::new(1);
+        for _ in 0..3 {
+            assert!(!detector.observe(AngularRateRaw::new(5, 5, 5)));
+        }
+        assert!(detector.observe(AngularRateRaw::new(5, 5, 5)));
+        assert_eq!(detector.mean(), Some([5.0; 3]));
+    }
+
+    #[test]
+    fn stillness_detector_threshold() {
+        // The samples 0 and 2 have a sample variance of 2.
+        let mut detector = StillnessDetector::<2>::new(1);
+        detector.observe(AngularRateRaw::new(0, 0, 0));
+        assert!(!detector.observe(AngularRateRaw::new(2, 2, 2)));
+
+        let mut detector = StillnessDetector::<2>::new(2);
+        detector.observe(AngularRateRaw::new(0, 0, 0));
+        assert!(detector.observe(AngularRateRaw::new(2, 2, 2)));
+    }
+
+    #[test]
+    fn stillness_detector_evicts_old_samples() {
+        let mut detector = StillnessDetector::<4>::new(1);
+        detector.observe(AngularRateRaw::new(1000, 0, 0));
+        for _ in 0..3 {
+            assert!(!detector.observe(AngularRateRaw::new(0, 0, 0)));
+        }
+        assert!(detector.observe(AngularRateRaw::new(0, 0, 0)));
+    }
+}
@@ -0,0 +1,156 @@
+//! Fixed-size telemetry records for downlinks.
+
+use crate::{AngularRateRaw, Measurement, StatusRegister, TimestampedMeasurement};
+
+/// A measurement with timestamp in a fixed-size wire layout, e.g. for radio or UART downlinks.
+///
+/// The record is encoded into [`LEN`](Self::LEN) bytes, with all values little endian:
+///
+/// | Offset | Size | Content                          |
+/// |--------|------|----------------------------------|
+/// | 0      | 8    | Timestamp in microseconds, `u64` |
+/// | 8      | 2    | X-axis angular rate, `i16`       |
+/// | 10     | 2    | Y-axis angular rate, `i16`       |
+/// | 12     | 2    | Z-axis angular rate, `i16`       |
+/// | 14     | 1    | Temperature, `i8`                |
+/// | 15     | 1    | `STATUS_REG`                     |
+///
+/// The in-memory representation matches the wire layout on little endian targets.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]
+pub struct TelemetryRecord {
+    /// The time in microseconds, see [`Clock`](crate::Clock).
+    pub timestamp_us: u64,
+    /// The angular rate reading.
+    pub rate: AngularRateRaw,
+    /// Temperature data (1LSB/deg, two's complement).
+    pub temp: i8,
+    /// The status register at the time of reading.
+    pub status: u8,
+}
+
+// The in-memory representation must match the wire layout.
+const _: () = assert!(core::mem::size_of::<TelemetryRecord>() == TelemetryRecord::LEN);
+
+impl TelemetryRecord {
+    /// The number of bytes of an encoded record.
+    pub const LEN: usize = 16;
+
+    /// Creates a record from a timestamp and a measurement.
+    pub const fn new(timestamp_us: u64, measurement: Measurement) -> Self {
+        Self {
+            timestamp_us,
+            rate: measurement.rate,
+            temp: measurement.temp,
+            status: measurement.status.into_bits(),
+        }
+    }
+
+    /// Returns the measurement held by the record.
+    pub const fn measurement(&self) -> Measurement {
+        Measurement {
+            rate: self.rate,
+            temp: self.temp,
+            status: StatusRegister::from_bits(self.status),
+        }
+    }
+
+    /// Encodes the record into its wire layout.
+    pub const fn to_bytes(&self) -> [u8; Self::LEN] {
+        let timestamp = self.timestamp_us.to_le_bytes();
+        let rate = self.rate.to_le_bytes();
+        [
+            timestamp[0],
+            timestamp[1],
+            timestamp[2],
+            timestamp[3],
+            timestamp[4],
+            timestamp[5],
+            timestamp[6],
+            timestamp[7],
+            rate[0],
+            rate[1],
+            rate[2],
+            rate[3],
+            rate[4],
+            rate[5],
+            self.temp as u8,
+            self.status,
+        ]
+    }
+
+    /// Decodes a record from its wire layout.
+    pub const fn from_bytes(bytes: [u8; Self::LEN]) -> Self {
+        Self {
+            timestamp_us: u64::from_le_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ]),
+            rate: AngularRateRaw::from_le_bytes([
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13],
+            ]),
+            temp: bytes[14] as i8,
+            status: bytes[15],
+        }
+    }
+}
+
+impl From<TimestampedMeasurement> for TelemetryRecord {
+    fn from(value: TimestampedMeasurement) -> Self {
+        Self::new(value.timestamp_us, value.measurement)
+    }
+}
+
+impl From<TelemetryRecord> for TimestampedMeasurement {
+    fn from(value: TelemetryRecord) -> Self {
+        TimestampedMeasurement::new(value.timestamp_us, value.measurement())
+    }
+}
+
+impl From<TelemetryRecord> for [u8; TelemetryRecord::LEN] {
+    fn from(value: TelemetryRecord) -> Self {
+        value.to_bytes()
+    }
+}
+
+impl From<[u8; TelemetryRecord::LEN]> for TelemetryRecord {
+    fn from(value: [u8; TelemetryRecord::LEN]) -> Self {
+        Self::from_bytes(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEASUREMENT: Measurement = Measurement {
+        rate: AngularRateRaw::new(0x0102, -2, 0x7FFF),
+        temp: -5,
+        status: StatusRegister::from_bits(0x0F),
+    };
+
+    #[test]
+    fn encodes_the_wire_layout() {
+        let record = TelemetryRecord::new(0x0807_0605_0403_0201, MEASUREMENT);
+        assert_eq!(
+            record.to_bytes(),
+            [
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x02, 0x01, 0xFE, 0xFF, 0xFF, 0x7F,
+                0xFB, 0x0F
+            ]
+        );
+    }
+
+    #[test]
+    fn decoding_round_trips() {
+        let record = TelemetryRecord::new(123_456_789, MEASUREMENT);
+        let bytes: [u8; TelemetryRecord::LEN] = record.into();
+        let decoded = TelemetryRecord::from(bytes);
+        assert_eq!(decoded, record);
+        assert_eq!(decoded.measurement(), MEASUREMENT);
+
+        let timestamped = TimestampedMeasurement::from(decoded);
+        assert_eq!(timestamped.timestamp_us, 123_456_789);
+        assert_eq!(TelemetryRecord::from(timestamped), record);
+    }
+}
@@ -0,0 +1,71 @@
+//! Tracking of the slowly updating temperature output.
+
+use crate::{consts, TimestampedMeasurement};
+
+/// Deduplicates temperature readings and timestamps genuine updates.
+///
+/// [`OUT_TEMP`](crate::RegisterAddress::OUT_TEMP) only updates at
+/// [`TEMPERATURE_UPDATE_RATE_HZ`](consts::TEMPERATURE_UPDATE_RATE_HZ), while it is typically
+/// read along with every angular rate sample. The tracker reports a reading only when the
+/// value changes, and [`is_due`](Self::is_due) tells whether reading the register is
+/// worthwhile at all.
+///
+/// Since the output has a resolution of 1 °C, an update with an unchanged value cannot be
+/// told apart from a repeated reading; the timestamp of the last change is kept in that case.
+///
+/// Timestamps are provided by the caller in microseconds from an arbitrary, monotonic clock.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TemperatureTracker {
+    last: Option<TimestampedMeasurement<i8>>,
+    last_read_us: Option<u64>,
+}
+
+impl TemperatureTracker {
+    /// Creates a new tracker.
+    pub const fn new() -> Self {
+        Self {
+            last: None,
+            last_read_us: None,
+        }
+    }
+
+    /// Records a reading and returns it if the value changed or is the first reading.
+    pub fn observe(&mut self, now_us: u64, temp: i8) -> Option<TimestampedMeasurement<i8>> {
+        self.last_read_us = Some(now_us);
+        match self.last {
+            Some(last) if last.measurement == temp => None,
+            _ => {
+                let reading = TimestampedMeasurement::new(now_us, temp);
+                self.last = Some(reading);
+                Some(reading)
+            }
+        }
+    }
+
+    /// Returns the last genuine update, if any.
+    pub const fn last(&self) -> Option<TimestampedMeasurement<i8>> {
+        self.last
+    }
+
+    /// Returns the last temperature value, if any.
+    pub fn value(&self) -> Option<i8> {
+        self.last.map(|reading| reading.measurement)
+    }
+
+    /// Determines whether the temperature output may have updated since the last reading,
+    /// i.e. whether at least one update period has passed.
+    pub fn is_due(&self, now_us: u64) -> bool {
+        match self.last_read_us {
+            Some(last) => {
+                now_us.saturating_sub(last) >= consts::TEMPERATURE_UPDATE_PERIOD_US as u64
+            }
+            None => true,
+        }
+    }
+
+    /// Forgets all readings, e.g. after powering the sensor down.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
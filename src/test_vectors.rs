@@ -0,0 +1,283 @@
+//! Known-good test vectors derived from the L3GD20 datasheet.
+//!
+//! Each vector pairs a raw register value with the field values it decodes to, so that
+//! downstream encoders, decoders and hardware-in-the-loop tests can be checked against
+//! authoritative data independently of this crate's register types.
+
+use crate::{Bandwidth, FifoMode, OutputDataRate, Sensitivity};
+
+/// An expected decoding of [`CTRL_REG1`](crate::RegisterAddress::CTRL_REG1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct ControlRegister1Vector {
+    pub bits: u8,
+    pub output_data_rate: OutputDataRate,
+    pub bandwidth: Bandwidth,
+    pub power_up: bool,
+    pub z_enable: bool,
+    pub y_enable: bool,
+    pub x_enable: bool,
+}
+
+/// Test vectors for [`CTRL_REG1`](crate::RegisterAddress::CTRL_REG1).
+pub const CTRL_REG1: &[ControlRegister1Vector] = &[
+    // Reset value: power-down mode, all axes enabled.
+    ControlRegister1Vector {
+        bits: 0b0000_0111,
+        output_data_rate: OutputDataRate::Hz95,
+        bandwidth: Bandwidth::Narrowest,
+        power_up: false,
+        z_enable: true,
+        y_enable: true,
+        x_enable: true,
+    },
+    // Normal mode at 95 Hz, all axes enabled.
+    ControlRegister1Vector {
+        bits: 0b0000_1111,
+        output_data_rate: OutputDataRate::Hz95,
+        bandwidth: Bandwidth::Narrowest,
+        power_up: true,
+        z_enable: true,
+        y_enable: true,
+        x_enable: true,
+    },
+    // Sleep mode: powered up, all axes disabled.
+    ControlRegister1Vector {
+        bits: 0b0000_1000,
+        output_data_rate: OutputDataRate::Hz95,
+        bandwidth: Bandwidth::Narrowest,
+        power_up: true,
+        z_enable: false,
+        y_enable: false,
+        x_enable: false,
+    },
+    // 380 Hz, 50 Hz cutoff, X axis only.
+    ControlRegister1Vector {
+        bits: 0b1010_1010,
+        output_data_rate: OutputDataRate::Hz380,
+        bandwidth: Bandwidth::Medium,
+        power_up: true,
+        z_enable: false,
+        y_enable: false,
+        x_enable: true,
+    },
+    // 760 Hz, 100 Hz cutoff, all axes enabled.
+    ControlRegister1Vector {
+        bits: 0b1111_1111,
+        output_data_rate: OutputDataRate::Hz760,
+        bandwidth: Bandwidth::Wide,
+        power_up: true,
+        z_enable: true,
+        y_enable: true,
+        x_enable: true,
+    },
+];
+
+/// An expected decoding of [`CTRL_REG4`](crate::RegisterAddress::CTRL_REG4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct ControlRegister4Vector {
+    pub bits: u8,
+    pub block_data_update: bool,
+    pub big_endian: bool,
+    pub full_scale: Sensitivity,
+    pub spi_serial_3wire: bool,
+}
+
+/// Test vectors for [`CTRL_REG4`](crate::RegisterAddress::CTRL_REG4).
+pub const CTRL_REG4: &[ControlRegister4Vector] = &[
+    ControlRegister4Vector {
+        bits: 0b0000_0000,
+        block_data_update: false,
+        big_endian: false,
+        full_scale: Sensitivity::D250,
+        spi_serial_3wire: false,
+    },
+    ControlRegister4Vector {
+        bits: 0b0001_0000,
+        block_data_update: false,
+        big_endian: false,
+        full_scale: Sensitivity::D500,
+        spi_serial_3wire: false,
+    },
+    ControlRegister4Vector {
+        bits: 0b1010_0000,
+        block_data_update: true,
+        big_endian: false,
+        full_scale: Sensitivity::D2000,
+        spi_serial_3wire: false,
+    },
+    ControlRegister4Vector {
+        bits: 0b0111_0001,
+        block_data_update: false,
+        big_endian: true,
+        full_scale: Sensitivity::D2000_11,
+        spi_serial_3wire: true,
+    },
+];
+
+/// An expected decoding of [`FIFO_CTRL_REG`](crate::RegisterAddress::FIFO_CTRL_REG).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct FifoControlRegisterVector {
+    pub bits: u8,
+    pub fifo_mode: FifoMode,
+    pub watermark: u8,
+}
+
+/// Test vectors for [`FIFO_CTRL_REG`](crate::RegisterAddress::FIFO_CTRL_REG).
+pub const FIFO_CTRL_REG: &[FifoControlRegisterVector] = &[
+    FifoControlRegisterVector {
+        bits: 0b0000_0000,
+        fifo_mode: FifoMode::Bypass,
+        watermark: 0,
+    },
+    FifoControlRegisterVector {
+        bits: 0b0011_1111,
+        fifo_mode: FifoMode::FIFO,
+        watermark: 31,
+    },
+    FifoControlRegisterVector {
+        bits: 0b0101_0000,
+        fifo_mode: FifoMode::Stream,
+        watermark: 16,
+    },
+    FifoControlRegisterVector {
+        bits: 0b0110_0001,
+        fifo_mode: FifoMode::StreamToFifo,
+        watermark: 1,
+    },
+    FifoControlRegisterVector {
+        bits: 0b1000_0000,
+        fifo_mode: FifoMode::BypassToStream,
+        watermark: 0,
+    },
+];
+
+/// An expected decoding of [`STATUS_REG`](crate::RegisterAddress::STATUS_REG).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct StatusRegisterVector {
+    pub bits: u8,
+    pub zyx_overrun: bool,
+    pub zyx_da: bool,
+    pub z_da: bool,
+    pub y_da: bool,
+    pub x_da: bool,
+}
+
+/// Test vectors for [`STATUS_REG`](crate::RegisterAddress::STATUS_REG).
+pub const STATUS_REG: &[StatusRegisterVector] = &[
+    StatusRegisterVector {
+        bits: 0b0000_0000,
+        zyx_overrun: false,
+        zyx_da: false,
+        z_da: false,
+        y_da: false,
+        x_da: false,
+    },
+    StatusRegisterVector {
+        bits: 0b0000_1111,
+        zyx_overrun: false,
+        zyx_da: true,
+        z_da: true,
+        y_da: true,
+        x_da: true,
+    },
+    StatusRegisterVector {
+        bits: 0b1111_1111,
+        zyx_overrun: true,
+        zyx_da: true,
+        z_da: true,
+        y_da: true,
+        x_da: true,
+    },
+    StatusRegisterVector {
+        bits: 0b0000_0001,
+        zyx_overrun: false,
+        zyx_da: false,
+        z_da: false,
+        y_da: false,
+        x_da: true,
+    },
+];
+
+/// An expected decoding of the output data block `OUT_X_L` (28h) through `OUT_Z_H` (2Dh)
+/// in little endian data order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub struct OutputVector {
+    pub bytes: [u8; 6],
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+}
+
+/// Test vectors for the output data block.
+pub const OUTPUT: &[OutputVector] = &[
+    OutputVector {
+        bytes: [0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        x: 0,
+        y: 0,
+        z: 0,
+    },
+    OutputVector {
+        bytes: [0x01, 0x00, 0xFF, 0xFF, 0x00, 0x01],
+        x: 1,
+        y: -1,
+        z: 256,
+    },
+    OutputVector {
+        bytes: [0xFF, 0x7F, 0x00, 0x80, 0x34, 0x12],
+        x: i16::MAX,
+        y: i16::MIN,
+        z: 0x1234,
+    },
+];
+
+/// An expected scaling of a raw reading, in millidegrees per second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub struct ScalingVector {
+    pub sensitivity: Sensitivity,
+    pub raw: i16,
+    pub mdps: f32,
+}
+
+/// Test vectors for scaling raw readings according to the sensitivities listed in the datasheet
+/// (8.75, 17.50 and 70 mdps/digit).
+pub const SCALING: &[ScalingVector] = &[
+    ScalingVector {
+        sensitivity: Sensitivity::D250,
+        raw: 1,
+        mdps: 8.75,
+    },
+    ScalingVector {
+        sensitivity: Sensitivity::D250,
+        raw: -1000,
+        mdps: -8_750.0,
+    },
+    ScalingVector {
+        sensitivity: Sensitivity::D500,
+        raw: 1,
+        mdps: 17.5,
+    },
+    ScalingVector {
+        sensitivity: Sensitivity::D500,
+        raw: 2000,
+        mdps: 35_000.0,
+    },
+    ScalingVector {
+        sensitivity: Sensitivity::D2000,
+        raw: 1,
+        mdps: 70.0,
+    },
+    ScalingVector {
+        sensitivity: Sensitivity::D2000,
+        raw: -32768,
+        mdps: -2_293_760.0,
+    },
+];
+
+/// The expected value of [`WHO_AM_I`](crate::RegisterAddress::WHO_AM_I).
+pub const WHO_AM_I: u8 = 0b1101_0100;
@@ -1,5 +1,7 @@
 //! Types used in the Gyroscope registers.
 
+use crate::{consts, AngularRateRaw};
+
 /// Gyroscope Output Data Rate
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -16,6 +18,30 @@ pub enum OutputDataRate {
 }
 
 impl OutputDataRate {
+    /// All output data rates in ascending order.
+    pub const ALL: [Self; 4] = [
+        OutputDataRate::Hz95,
+        OutputDataRate::Hz190,
+        OutputDataRate::Hz380,
+        OutputDataRate::Hz760,
+    ];
+
+    /// Iterates [`ALL`](Self::ALL) values.
+    pub fn iter() -> core::array::IntoIter<Self, 4> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the nominal output data rate in Hertz.
+    pub const fn hz(self) -> u16 {
+        consts::OUTPUT_DATA_RATE_HZ[self as usize]
+    }
+
+    /// Returns the nominal sample period in microseconds, rounded up.
+    pub const fn period_us(self) -> u32 {
+        let hz = self.hz() as u32;
+        (1_000_000 + hz - 1) / hz
+    }
+
     /// Converts the value into an `u8`.
     pub const fn into_bits(self) -> u8 {
         self as u8
@@ -76,35 +102,23 @@ pub enum Bandwidth {
 }
 
 impl Bandwidth {
+    /// All bandwidths from narrowest to widest.
+    pub const ALL: [Self; 4] = [
+        Bandwidth::Narrowest,
+        Bandwidth::Narrow,
+        Bandwidth::Medium,
+        Bandwidth::Wide,
+    ];
+
+    /// Iterates [`ALL`](Self::ALL) values.
+    pub fn iter() -> core::array::IntoIter<Self, 4> {
+        Self::ALL.into_iter()
+    }
+
     /// Determines the bandwidth in Hertz at the given output data rate.
     #[must_use]
-    pub fn hz_at(&self, odr: OutputDataRate) -> f32 {
-        match self {
-            Bandwidth::Narrowest => match odr {
-                OutputDataRate::Hz95 => 12.5,
-                OutputDataRate::Hz190 => 12.5,
-                OutputDataRate::Hz380 => 20.0,
-                OutputDataRate::Hz760 => 30.0,
-            },
-            Bandwidth::Narrow => match odr {
-                OutputDataRate::Hz95 => 25.0,
-                OutputDataRate::Hz190 => 25.0,
-                OutputDataRate::Hz380 => 25.0,
-                OutputDataRate::Hz760 => 35.0,
-            },
-            Bandwidth::Medium => match odr {
-                OutputDataRate::Hz95 => 25.0,
-                OutputDataRate::Hz190 => 50.0,
-                OutputDataRate::Hz380 => 50.0,
-                OutputDataRate::Hz760 => 50.0,
-            },
-            Bandwidth::Wide => match odr {
-                OutputDataRate::Hz95 => 25.0,
-                OutputDataRate::Hz190 => 70.0,
-                OutputDataRate::Hz380 => 100.0,
-                OutputDataRate::Hz760 => 100.0,
-            },
-        }
+    pub const fn hz_at(&self, odr: OutputDataRate) -> f32 {
+        consts::BANDWIDTH_HZ[odr as usize][*self as usize]
     }
 
     /// Determines the square root of the bandwidth at the given output data rate.
@@ -113,34 +127,8 @@ impl Bandwidth {
     /// the rate noise density is 0.03 dps/√Hz, so multiplying 0.03 with this result
     /// gives an effective value.
     #[must_use]
-    pub fn sqrt_hz_at(&self, odr: OutputDataRate) -> f32 {
-        #[allow(clippy::excessive_precision)]
-        match self {
-            Bandwidth::Narrowest => match odr {
-                OutputDataRate::Hz95 => 3.5355339059327378,  // √(12.5 Hz)
-                OutputDataRate::Hz190 => 3.5355339059327378, // √(12.5 Hz)
-                OutputDataRate::Hz380 => 4.47213595499958,   // √(20.0 Hz)
-                OutputDataRate::Hz760 => 5.477225575051661,  // √(30.0 Hz)
-            },
-            Bandwidth::Narrow => match odr {
-                OutputDataRate::Hz95 => 5.0,                // √(25.0 Hz)
-                OutputDataRate::Hz190 => 5.0,               // √(25.0 Hz)
-                OutputDataRate::Hz380 => 25.0,              // √(25.0 Hz)
-                OutputDataRate::Hz760 => 5.916079783099616, // √(35.0 Hz)
-            },
-            Bandwidth::Medium => match odr {
-                OutputDataRate::Hz95 => 5.0,                 // √(25.0 Hz)
-                OutputDataRate::Hz190 => 7.0710678118654755, // √(50.0 Hz)
-                OutputDataRate::Hz380 => 7.0710678118654755, // √(50.0 Hz)
-                OutputDataRate::Hz760 => 7.0710678118654755, // √(50.0 Hz)
-            },
-            Bandwidth::Wide => match odr {
-                OutputDataRate::Hz95 => 5.0,                // √(25.0 Hz)
-                OutputDataRate::Hz190 => 8.366600265340756, // √(70.0 Hz)
-                OutputDataRate::Hz380 => 10.0,              // √(100.0 Hz)
-                OutputDataRate::Hz760 => 10.0,              // √(100.0 Hz)
-            },
-        }
+    pub const fn sqrt_hz_at(&self, odr: OutputDataRate) -> f32 {
+        consts::BANDWIDTH_SQRT_HZ[odr as usize][*self as usize]
     }
 
     /// Converts the value into an `u8`.
@@ -175,6 +163,19 @@ pub enum HighpassFilterMode {
 }
 
 impl HighpassFilterMode {
+    /// All high-pass filter modes in register order.
+    pub const ALL: [Self; 4] = [
+        HighpassFilterMode::NormalModeResetFilter,
+        HighpassFilterMode::ReferenceSignal,
+        HighpassFilterMode::NormalMode,
+        HighpassFilterMode::AutoresetOnInterrupt,
+    ];
+
+    /// Iterates [`ALL`](Self::ALL) values.
+    pub fn iter() -> core::array::IntoIter<Self, 4> {
+        Self::ALL.into_iter()
+    }
+
     /// Converts the value into an `u8`.
     pub const fn into_bits(self) -> u8 {
         self as u8
@@ -231,6 +232,89 @@ pub enum Sensitivity {
 }
 
 impl Sensitivity {
+    /// All distinct full scales in ascending order, without the duplicate [`Sensitivity::D2000_11`].
+    pub const ALL: [Self; 3] = [Sensitivity::D250, Sensitivity::D500, Sensitivity::D2000];
+
+    /// Iterates [`ALL`](Self::ALL) values.
+    pub fn iter() -> core::array::IntoIter<Self, 3> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the sensitivity in millidegrees per second per digit.
+    ///
+    /// * 8.75 mdps/digit for [`Sensitivity::D250`]
+    /// * 17.50 mdps/digit for [`Sensitivity::D500`]
+    /// * 70 mdps/digit for [`Sensitivity::D2000`] and [`Sensitivity::D2000_11`]
+    #[must_use]
+    pub const fn mdps_per_digit(&self) -> f32 {
+        consts::SENSITIVITY_MDPS_PER_DIGIT[*self as usize]
+    }
+
+    /// Returns the sensitivity in hundredths of millidegrees per second per digit,
+    /// i.e. the exact datasheet factor as an integer.
+    ///
+    /// * 875 for [`Sensitivity::D250`] (8.75 mdps/digit)
+    /// * 1750 for [`Sensitivity::D500`] (17.50 mdps/digit)
+    /// * 7000 for [`Sensitivity::D2000`] and [`Sensitivity::D2000_11`] (70 mdps/digit)
+    pub const fn centi_mdps_per_digit(self) -> u32 {
+        match self {
+            Sensitivity::D250 => 875,
+            Sensitivity::D500 => 1750,
+            Sensitivity::D2000 | Sensitivity::D2000_11 => 7000,
+        }
+    }
+
+    /// Returns the zero-rate level tolerance in degrees per second, i.e. the largest
+    /// output a stationary sensor may report according to the datasheet.
+    ///
+    /// * ±10 dps for [`Sensitivity::D250`]
+    /// * ±15 dps for [`Sensitivity::D500`]
+    /// * ±75 dps for [`Sensitivity::D2000`] and [`Sensitivity::D2000_11`]
+    #[must_use]
+    pub const fn zero_rate_level_dps(self) -> f32 {
+        match self {
+            Sensitivity::D250 => consts::ZERO_RATE_LEVEL_DPS_250,
+            Sensitivity::D500 => consts::ZERO_RATE_LEVEL_DPS_500,
+            Sensitivity::D2000 | Sensitivity::D2000_11 => consts::ZERO_RATE_LEVEL_DPS_2000,
+        }
+    }
+
+    /// Returns the zero-rate level tolerance in millidegrees per second.
+    /// See [`zero_rate_level_dps`](Self::zero_rate_level_dps).
+    pub const fn zero_rate_level_mdps(self) -> u32 {
+        match self {
+            Sensitivity::D250 => 10_000,
+            Sensitivity::D500 => 15_000,
+            Sensitivity::D2000 | Sensitivity::D2000_11 => 75_000,
+        }
+    }
+
+    /// Determines whether a raw reading of a stationary sensor is within the zero-rate level
+    /// tolerance on all axes, using exact integer arithmetic.
+    pub const fn is_within_zero_rate_level(self, raw: AngularRateRaw) -> bool {
+        let limit = self.zero_rate_level_mdps() as u64 * 100;
+        let factor = self.centi_mdps_per_digit() as u64;
+        raw.x.unsigned_abs() as u64 * factor <= limit
+            && raw.y.unsigned_abs() as u64 * factor <= limit
+            && raw.z.unsigned_abs() as u64 * factor <= limit
+    }
+
+    /// Returns the full scale in degrees per second.
+    pub const fn dps(self) -> u16 {
+        consts::FULL_SCALE_DPS[self as usize]
+    }
+
+    /// Returns the smallest (i.e. most sensitive) full scale covering the specified
+    /// maximum angular rate in degrees per second.
+    ///
+    /// The rate is multiplied by `headroom` first, e.g. `1.2` for a 20% margin.
+    /// Returns `None` if even 2000 dps are insufficient.
+    #[must_use]
+    pub fn smallest_for(max_dps: f32, headroom: f32) -> Option<Self> {
+        let required = if max_dps < 0.0 { -max_dps } else { max_dps } * headroom;
+        Sensitivity::iter().find(|sensitivity| f32::from(sensitivity.dps()) >= required)
+    }
+
     /// Converts the value into an `u8`.
     pub const fn into_bits(self) -> u8 {
         self as u8
@@ -268,19 +352,43 @@ pub enum FifoMode {
 }
 
 impl FifoMode {
+    /// All FIFO modes in register order.
+    pub const ALL: [Self; 5] = [
+        FifoMode::Bypass,
+        FifoMode::FIFO,
+        FifoMode::Stream,
+        FifoMode::StreamToFifo,
+        FifoMode::BypassToStream,
+    ];
+
+    /// Iterates [`ALL`](Self::ALL) values.
+    pub fn iter() -> core::array::IntoIter<Self, 5> {
+        Self::ALL.into_iter()
+    }
+
     /// Converts the value into an `u8`.
     pub const fn into_bits(self) -> u8 {
         self as u8
     }
 
-    pub(crate) const fn from_bits(value: u8) -> Self {
+    /// Converts the 3-bit field value, returning `None` for the reserved values
+    /// `0b101` to `0b111`.
+    pub const fn try_from_bits(value: u8) -> Option<Self> {
         match value {
-            0b000 => FifoMode::Bypass,
-            0b001 => FifoMode::FIFO,
-            0b010 => FifoMode::Stream,
-            0b011 => FifoMode::StreamToFifo,
-            0b100 => FifoMode::BypassToStream,
-            _ => unreachable!(),
+            0b000 => Some(FifoMode::Bypass),
+            0b001 => Some(FifoMode::FIFO),
+            0b010 => Some(FifoMode::Stream),
+            0b011 => Some(FifoMode::StreamToFifo),
+            0b100 => Some(FifoMode::BypassToStream),
+            _ => None,
+        }
+    }
+
+    /// Converts the 3-bit field value, decoding reserved values as [`Bypass`](Self::Bypass).
+    pub(crate) const fn from_bits(value: u8) -> Self {
+        match Self::try_from_bits(value) {
+            Some(mode) => mode,
+            None => FifoMode::Bypass,
         }
     }
 }
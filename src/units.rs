@@ -0,0 +1,504 @@
+//! Scaled angular rates and unit conversions.
+
+use crate::{AngularRateRaw, Sensitivity};
+use core::f32::consts::PI;
+use core::marker::PhantomData;
+
+/// Units of angular velocity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AngularUnit {
+    /// Degrees per second (dps), the native unit of the sensor.
+    DegreesPerSecond,
+    /// Radians per second (rad/s).
+    RadiansPerSecond,
+    /// Revolutions per minute (rpm).
+    RevolutionsPerMinute,
+}
+
+impl AngularUnit {
+    /// Converts a value in degrees per second into this unit.
+    #[must_use]
+    pub fn from_dps(&self, dps: f32) -> f32 {
+        match self {
+            AngularUnit::DegreesPerSecond => dps,
+            AngularUnit::RadiansPerSecond => dps * (PI / 180.0),
+            AngularUnit::RevolutionsPerMinute => dps / 6.0,
+        }
+    }
+
+    /// Converts a value in this unit into degrees per second.
+    #[must_use]
+    pub fn to_dps(&self, value: f32) -> f32 {
+        match self {
+            AngularUnit::DegreesPerSecond => value,
+            AngularUnit::RadiansPerSecond => value * (180.0 / PI),
+            AngularUnit::RevolutionsPerMinute => value * 6.0,
+        }
+    }
+
+    /// Converts a value from this unit into another unit.
+    #[must_use]
+    pub fn convert(&self, value: f32, target: AngularUnit) -> f32 {
+        target.from_dps(self.to_dps(value))
+    }
+}
+
+/// Angular rate of all three axes, scaled to degrees per second.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AngularRate {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl AngularRate {
+    /// Creates a new angular rate from values in degrees per second.
+    pub const fn from_dps(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a new angular rate from values in the specified unit.
+    #[must_use]
+    pub fn from_unit(x: f32, y: f32, z: f32, unit: AngularUnit) -> Self {
+        Self {
+            x: unit.to_dps(x),
+            y: unit.to_dps(y),
+            z: unit.to_dps(z),
+        }
+    }
+
+    /// Scales a raw reading according to the configured [`Sensitivity`].
+    #[must_use]
+    pub fn from_raw(raw: AngularRateRaw, sensitivity: Sensitivity) -> Self {
+        let dps_per_digit = sensitivity.mdps_per_digit() / 1000.0;
+        Self {
+            x: raw.x as f32 * dps_per_digit,
+            y: raw.y as f32 * dps_per_digit,
+            z: raw.z as f32 * dps_per_digit,
+        }
+    }
+
+    /// Returns the X-axis angular rate in degrees per second.
+    pub const fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Returns the Y-axis angular rate in degrees per second.
+    pub const fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Returns the Z-axis angular rate in degrees per second.
+    pub const fn z(&self) -> f32 {
+        self.z
+    }
+
+    /// Returns the magnitude of the angular rate vector in degrees per second.
+    #[must_use]
+    pub fn magnitude(&self) -> f32 {
+        sqrt(self.magnitude_squared())
+    }
+
+    /// Returns the squared magnitude of the angular rate vector in (degrees per second)².
+    #[must_use]
+    pub fn magnitude_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Returns the X, Y and Z values in degrees per second.
+    pub const fn to_dps(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Returns the X, Y and Z values in radians per second.
+    #[must_use]
+    pub fn to_rad_per_sec(&self) -> [f32; 3] {
+        self.to_unit(AngularUnit::RadiansPerSecond)
+    }
+
+    /// Returns the X, Y and Z values in revolutions per minute.
+    #[must_use]
+    pub fn to_rpm(&self) -> [f32; 3] {
+        self.to_unit(AngularUnit::RevolutionsPerMinute)
+    }
+
+    /// Returns the X, Y and Z values in the specified unit.
+    #[must_use]
+    pub fn to_unit(&self, unit: AngularUnit) -> [f32; 3] {
+        [
+            unit.from_dps(self.x),
+            unit.from_dps(self.y),
+            unit.from_dps(self.z),
+        ]
+    }
+}
+
+impl From<AngularRate> for [f32; 3] {
+    fn from(value: AngularRate) -> Self {
+        value.to_dps()
+    }
+}
+
+impl AngularRateRaw {
+    /// Scales the reading according to the configured [`Sensitivity`].
+    #[must_use]
+    pub fn scale(self, sensitivity: Sensitivity) -> AngularRate {
+        AngularRate::from_raw(self, sensitivity)
+    }
+}
+
+/// Rounding behavior of scaling conversions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Rounding {
+    /// Discards the fractional part, i.e. rounds toward zero.
+    Truncate,
+    /// Rounds to the nearest integer, with halves rounded away from zero.
+    #[default]
+    Nearest,
+    /// Rounds any fractional part away from zero, e.g. so that a threshold is never
+    /// smaller than requested.
+    AwayFromZero,
+}
+
+impl Rounding {
+    /// Divides using this rounding behavior. The divisor must be positive.
+    pub const fn div(self, value: i64, divisor: i64) -> i64 {
+        let offset = match self {
+            Rounding::Truncate => 0,
+            Rounding::Nearest => divisor / 2,
+            Rounding::AwayFromZero => divisor - 1,
+        };
+        if value < 0 {
+            (value - offset) / divisor
+        } else {
+            (value + offset) / divisor
+        }
+    }
+
+    /// Rounds a value to an integer using this rounding behavior, saturating at the `i32`
+    /// range. `NaN` is converted to zero.
+    #[must_use]
+    pub fn round(self, value: f32) -> i32 {
+        // Saturating cast, truncating toward zero.
+        let truncated = value as i32;
+        let fraction = value - truncated as f32;
+        let magnitude = if fraction < 0.0 { -fraction } else { fraction };
+        let away = match self {
+            Rounding::Truncate => false,
+            Rounding::Nearest => magnitude >= 0.5,
+            Rounding::AwayFromZero => magnitude > 0.0,
+        };
+        match (away, value < 0.0) {
+            (false, _) => truncated,
+            (true, false) => truncated.saturating_add(1),
+            (true, true) => truncated.saturating_sub(1),
+        }
+    }
+}
+
+/// Converts a raw reading into millidegrees per second using integer arithmetic only.
+///
+/// The exact datasheet factor is used (see [`Sensitivity::centi_mdps_per_digit`]) and the
+/// result is rounded to the nearest integer, with halves rounded away from zero.
+/// See [`raw_to_mdps_rounded`] for other rounding behaviors.
+pub const fn raw_to_mdps(raw: i16, sensitivity: Sensitivity) -> i32 {
+    raw_to_mdps_rounded(raw, sensitivity, Rounding::Nearest)
+}
+
+/// Converts a raw reading into millidegrees per second using integer arithmetic only,
+/// with the specified rounding behavior.
+pub const fn raw_to_mdps_rounded(raw: i16, sensitivity: Sensitivity, rounding: Rounding) -> i32 {
+    rounding.div(raw as i64 * sensitivity.centi_mdps_per_digit() as i64, 100) as i32
+}
+
+/// Converts an angular rate in millidegrees per second into a raw value using integer
+/// arithmetic only, e.g. for thresholds.
+///
+/// The result is rounded to the nearest digit, with halves rounded away from zero, and
+/// saturates at the `i16` range. See [`mdps_to_raw_rounded`] for other rounding behaviors.
+pub const fn mdps_to_raw(mdps: i32, sensitivity: Sensitivity) -> i16 {
+    mdps_to_raw_rounded(mdps, sensitivity, Rounding::Nearest)
+}
+
+/// Converts an angular rate in millidegrees per second into a raw value using integer
+/// arithmetic only, with the specified rounding behavior. The result saturates at the
+/// `i16` range.
+pub const fn mdps_to_raw_rounded(mdps: i32, sensitivity: Sensitivity, rounding: Rounding) -> i16 {
+    let raw = rounding.div(mdps as i64 * 100, sensitivity.centi_mdps_per_digit() as i64);
+    saturate_i16(raw)
+}
+
+/// Converts an angular rate in degrees per second into a raw value with the specified
+/// rounding behavior, e.g. for calibration offsets. The result saturates at the `i16` range.
+#[must_use]
+pub fn dps_to_raw(dps: f32, sensitivity: Sensitivity, rounding: Rounding) -> i16 {
+    let digits = dps * 1000.0 / sensitivity.mdps_per_digit();
+    saturate_i16(rounding.round(digits) as i64)
+}
+
+pub(crate) const fn saturate_i16(value: i64) -> i16 {
+    if value > i16::MAX as i64 {
+        i16::MAX
+    } else if value < i16::MIN as i64 {
+        i16::MIN
+    } else {
+        value as i16
+    }
+}
+
+/// A unit of angular velocity known at compile time, see [`AngularRateIn`].
+///
+/// This trait is sealed and implemented for [`Dps`], [`RadPerSec`] and [`Rpm`].
+pub trait RateUnit: unit_sealed::Sealed + Copy + Default {
+    /// The unit represented by the marker type.
+    const UNIT: AngularUnit;
+}
+
+mod unit_sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! rate_unit {
+    ($(#[$meta:meta])* $type:ident, $unit:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $type;
+
+        impl unit_sealed::Sealed for $type {}
+
+        impl RateUnit for $type {
+            const UNIT: AngularUnit = $unit;
+        }
+    };
+}
+
+rate_unit!(
+    /// Marker for degrees per second (dps).
+    Dps,
+    AngularUnit::DegreesPerSecond
+);
+rate_unit!(
+    /// Marker for radians per second (rad/s).
+    RadPerSec,
+    AngularUnit::RadiansPerSecond
+);
+rate_unit!(
+    /// Marker for revolutions per minute (rpm).
+    Rpm,
+    AngularUnit::RevolutionsPerMinute
+);
+
+/// Angular rate of all three axes in the unit `U`, e.g. `AngularRateIn<RadPerSec>`.
+///
+/// The unit is part of the type, so values in different units cannot be mixed up
+/// without an explicit [`convert`](Self::convert).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AngularRateIn<U: RateUnit> {
+    x: f32,
+    y: f32,
+    z: f32,
+    unit: PhantomData<U>,
+}
+
+impl<U: RateUnit> AngularRateIn<U> {
+    /// Creates a new angular rate from values in the unit `U`.
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            unit: PhantomData,
+        }
+    }
+
+    /// Returns the X-axis angular rate.
+    pub const fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Returns the Y-axis angular rate.
+    pub const fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Returns the Z-axis angular rate.
+    pub const fn z(&self) -> f32 {
+        self.z
+    }
+
+    /// Returns the X, Y and Z values.
+    pub const fn to_array(&self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Returns the unit of the values.
+    pub const fn unit(&self) -> AngularUnit {
+        U::UNIT
+    }
+
+    /// Converts the values into the unit `V`.
+    #[must_use]
+    pub fn convert<V: RateUnit>(&self) -> AngularRateIn<V> {
+        let [x, y, z] = self.untyped().to_unit(V::UNIT);
+        AngularRateIn::new(x, y, z)
+    }
+
+    /// Returns the angular rate without compile-time unit.
+    #[must_use]
+    pub fn untyped(&self) -> AngularRate {
+        AngularRate::from_unit(self.x, self.y, self.z, U::UNIT)
+    }
+}
+
+impl<U: RateUnit> core::ops::Add for AngularRateIn<U> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<U: RateUnit> core::ops::Sub for AngularRateIn<U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<U: RateUnit> core::ops::Mul<f32> for AngularRateIn<U> {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<U: RateUnit> From<AngularRate> for AngularRateIn<U> {
+    fn from(value: AngularRate) -> Self {
+        value.to_typed()
+    }
+}
+
+impl<U: RateUnit> From<AngularRateIn<U>> for AngularRate {
+    fn from(value: AngularRateIn<U>) -> Self {
+        value.untyped()
+    }
+}
+
+impl AngularRate {
+    /// Returns the angular rate in the unit `U`, e.g. `rate.to_typed::<RadPerSec>()`.
+    #[must_use]
+    pub fn to_typed<U: RateUnit>(&self) -> AngularRateIn<U> {
+        let [x, y, z] = self.to_unit(U::UNIT);
+        AngularRateIn::new(x, y, z)
+    }
+}
+
+/// Square root using Newton's method, as `f32::sqrt` is unavailable in `no_std`.
+pub(crate) fn sqrt(value: f32) -> f32 {
+    if value.is_nan() || value < 0.0 {
+        return f32::NAN;
+    }
+    if value == 0.0 || value.is_infinite() {
+        return value;
+    }
+
+    let mut x = f32::from_bits((value.to_bits() >> 1) + 0x1FBD_1DF5);
+    for _ in 0..4 {
+        x = 0.5 * (x + value / x);
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [Rounding; 3] = [
+        Rounding::Truncate,
+        Rounding::Nearest,
+        Rounding::AwayFromZero,
+    ];
+
+    #[test]
+    fn div_rounds_symmetrically() {
+        let expected = [(7, 8, 8), (7, 7, 8), (7, 7, 7)];
+        for ((value, divisor), (truncate, nearest, away)) in
+            [(15, 2), (29, 4), (21, 3)].into_iter().zip(expected)
+        {
+            assert_eq!(
+                MODES.map(|mode| mode.div(value, divisor)),
+                [truncate, nearest, away]
+            );
+            assert_eq!(
+                MODES.map(|mode| mode.div(-value, divisor)),
+                [-truncate, -nearest, -away]
+            );
+        }
+    }
+
+    #[test]
+    fn round_handles_halves_and_limits() {
+        assert_eq!(MODES.map(|mode| mode.round(1.5)), [1, 2, 2]);
+        assert_eq!(MODES.map(|mode| mode.round(-1.5)), [-1, -2, -2]);
+        assert_eq!(MODES.map(|mode| mode.round(1.25)), [1, 1, 2]);
+        assert_eq!(MODES.map(|mode| mode.round(-3.0)), [-3, -3, -3]);
+        assert_eq!(MODES.map(|mode| mode.round(f32::NAN)), [0, 0, 0]);
+        assert_eq!(MODES.map(|mode| mode.round(1e10)), [i32::MAX; 3]);
+        assert_eq!(MODES.map(|mode| mode.round(-1e10)), [i32::MIN; 3]);
+    }
+
+    #[test]
+    fn raw_to_mdps_uses_the_exact_factor() {
+        assert_eq!(raw_to_mdps(1, Sensitivity::D250), 9);
+        assert_eq!(raw_to_mdps(-1, Sensitivity::D250), -9);
+        assert_eq!(raw_to_mdps(2, Sensitivity::D250), 18);
+        assert_eq!(raw_to_mdps(-2, Sensitivity::D250), -18);
+        assert_eq!(raw_to_mdps(i16::MAX, Sensitivity::D2000), 2_293_690);
+        assert_eq!(raw_to_mdps(i16::MIN, Sensitivity::D2000), -2_293_760);
+    }
+
+    #[test]
+    fn raw_to_mdps_rounded_applies_the_rounding() {
+        let convert = |raw| MODES.map(|mode| raw_to_mdps_rounded(raw, Sensitivity::D250, mode));
+        assert_eq!(convert(1), [8, 9, 9]);
+        assert_eq!(convert(-1), [-8, -9, -9]);
+        assert_eq!(convert(4), [35, 35, 35]);
+    }
+
+    #[test]
+    fn mdps_to_raw_rounds_and_saturates() {
+        let convert = |mdps| MODES.map(|mode| mdps_to_raw_rounded(mdps, Sensitivity::D250, mode));
+        assert_eq!(convert(1000), [114, 114, 115]);
+        assert_eq!(convert(-1000), [-114, -114, -115]);
+        assert_eq!(convert(3_000_000), [i16::MAX; 3]);
+        assert_eq!(convert(-3_000_000), [i16::MIN; 3]);
+        assert_eq!(mdps_to_raw(35, Sensitivity::D2000), 1);
+        assert_eq!(mdps_to_raw(-35, Sensitivity::D2000), -1);
+        assert_eq!(mdps_to_raw(34, Sensitivity::D2000), 0);
+    }
+
+    #[test]
+    fn mdps_round_trips_every_raw_value() {
+        for sensitivity in Sensitivity::ALL {
+            for raw in i16::MIN..=i16::MAX {
+                assert_eq!(mdps_to_raw(raw_to_mdps(raw, sensitivity), sensitivity), raw);
+            }
+        }
+    }
+
+    #[test]
+    fn dps_to_raw_rounds_and_saturates() {
+        let convert = |dps| MODES.map(|mode| dps_to_raw(dps, Sensitivity::D250, mode));
+        assert_eq!(convert(1.0), [114, 114, 115]);
+        assert_eq!(convert(-1.0), [-114, -114, -115]);
+        assert_eq!(convert(1000.0), [i16::MAX; 3]);
+        assert_eq!(convert(-1000.0), [i16::MIN; 3]);
+    }
+}
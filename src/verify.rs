@@ -0,0 +1,169 @@
+//! Read-back verification of register writes.
+
+use crate::{ControlRegister5, RegisterAddress, RegisterBus};
+
+/// A register that read back a different value than was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerificationError {
+    /// The register address.
+    pub addr: RegisterAddress,
+    /// The value written.
+    pub wrote: u8,
+    /// The value read back.
+    pub read: u8,
+}
+
+impl VerificationError {
+    /// Returns the bits that differ between the written and the read value,
+    /// ignoring self-clearing bits.
+    pub const fn mismatch(&self) -> u8 {
+        (self.wrote ^ self.read) & verification_mask(self.addr)
+    }
+}
+
+impl core::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "register {} ({:02X}h) read back {:02X}h after writing {:02X}h",
+            self.addr.name(),
+            self.addr.addr(),
+            self.read,
+            self.wrote
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for VerificationError {}
+
+/// An error when writing registers using [`write_verified`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteVerifyError<E> {
+    /// The bus reported an error.
+    Bus(E),
+    /// A register read back a different value than was written.
+    Mismatch(VerificationError),
+}
+
+impl<E> From<VerificationError> for WriteVerifyError<E> {
+    fn from(value: VerificationError) -> Self {
+        WriteVerifyError::Mismatch(value)
+    }
+}
+
+impl<E> core::fmt::Display for WriteVerifyError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WriteVerifyError::Bus(error) => write!(f, "bus error: {error}"),
+            WriteVerifyError::Mismatch(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<E> std::error::Error for WriteVerifyError<E> where E: core::fmt::Debug + core::fmt::Display {}
+
+/// Compares a written value with the value read back from a register.
+///
+/// Self-clearing bits, such as [`ControlRegister5::boot`], are ignored.
+pub const fn verify_register(
+    addr: RegisterAddress,
+    wrote: u8,
+    read: u8,
+) -> Result<(), VerificationError> {
+    let error = VerificationError { addr, wrote, read };
+    if error.mismatch() == 0 {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+/// Writes registers and reads back each one to verify the write, e.g. to detect marginal bus
+/// wiring or counterfeit parts.
+///
+/// `writes` are performed in order, e.g. using [`Config::write_sequence`](crate::Config::write_sequence).
+/// `write` and `read` access a single register. Writing stops at the first bus error or mismatch.
+pub fn write_verified<I, W, R, E>(
+    writes: I,
+    mut write: W,
+    mut read: R,
+) -> Result<(), WriteVerifyError<E>>
+where
+    I: IntoIterator<Item = (RegisterAddress, u8)>,
+    W: FnMut(RegisterAddress, u8) -> Result<(), E>,
+    R: FnMut(RegisterAddress) -> Result<u8, E>,
+{
+    for (addr, value) in writes {
+        write(addr, value).map_err(WriteVerifyError::Bus)?;
+        let read_back = read(addr).map_err(WriteVerifyError::Bus)?;
+        verify_register(addr, value, read_back)?;
+    }
+    Ok(())
+}
+
+/// Writes registers through a [`RegisterBus`] and reads back each one, see [`write_verified`].
+pub fn write_verified_bus<B, I>(bus: &mut B, writes: I) -> Result<(), WriteVerifyError<B::Error>>
+where
+    B: RegisterBus,
+    I: IntoIterator<Item = (RegisterAddress, u8)>,
+{
+    let bus = core::cell::RefCell::new(bus);
+    write_verified(
+        writes,
+        |addr, value| bus.borrow_mut().write_register(addr, value),
+        |addr| bus.borrow_mut().read_register(addr),
+    )
+}
+
+/// Returns the bits of a register that retain their written value.
+const fn verification_mask(addr: RegisterAddress) -> u8 {
+    match addr {
+        RegisterAddress::CTRL_REG5 => !ControlRegister5::BOOT_MASK,
+        _ => 0xFF,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BusTransaction, Replay};
+
+    #[test]
+    fn boot_bit_is_ignored() {
+        assert_eq!(
+            verify_register(RegisterAddress::CTRL_REG5, 0x80, 0x00),
+            Ok(())
+        );
+        assert!(verify_register(RegisterAddress::CTRL_REG4, 0x80, 0x00).is_err());
+    }
+
+    #[test]
+    fn write_verified_bus_reports_mismatch() {
+        let log = [
+            BusTransaction::write(RegisterAddress::CTRL_REG2, &[0x21]),
+            BusTransaction::read(RegisterAddress::CTRL_REG2, &[0x21]),
+            BusTransaction::write(RegisterAddress::CTRL_REG1, &[0x0F]),
+            BusTransaction::read(RegisterAddress::CTRL_REG1, &[0x07]),
+        ];
+        let writes = [
+            (RegisterAddress::CTRL_REG2, 0x21),
+            (RegisterAddress::CTRL_REG1, 0x0F),
+        ];
+        let error = write_verified_bus(&mut Replay::new(&log), writes).unwrap_err();
+        assert_eq!(
+            error,
+            WriteVerifyError::Mismatch(VerificationError {
+                addr: RegisterAddress::CTRL_REG1,
+                wrote: 0x0F,
+                read: 0x07,
+            })
+        );
+    }
+}